@@ -0,0 +1,60 @@
+use bn254::fp::Fp;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+
+fn bench_mul(c: &mut Criterion) {
+    let a = Fp::new(0x1234_5678_9abc_def0_u64.into());
+    let b = Fp::new(0x0fed_cba9_8765_4321_u64.into());
+    c.bench_function("fp mul", |bench| bench.iter(|| black_box(a) * black_box(b)));
+}
+
+/// Montgomery multiplication against the `BigUint` residue `Fp` used to
+/// hold, `(a * b) % p`, on full-width operands. Each chain multiplies 1000
+/// times by the previous product, so the operands stay 254 bits wide.
+fn bench_mul_baseline(c: &mut Criterion) {
+    let p = BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088696311157297823662689037894645226208583",
+        10,
+    )
+    .unwrap();
+    let a_big = &p - 3u32;
+    let b_big = (&p >> 1u32) + 12345u32;
+    let (a, b) = (Fp::new(a_big.clone()), Fp::new(b_big.clone()));
+
+    c.bench_function("fp mul chain x1000", |bench| {
+        bench.iter(|| {
+            let mut x = black_box(a);
+            for _ in 0..1000 {
+                x = x * b;
+            }
+            x
+        })
+    });
+    c.bench_function("fp mul chain x1000 biguint", |bench| {
+        bench.iter(|| {
+            let mut x = black_box(&a_big).clone();
+            for _ in 0..1000 {
+                x = (&x * &b_big) % &p;
+            }
+            x
+        })
+    });
+}
+
+fn bench_pow(c: &mut Criterion) {
+    let a = Fp::from(0x1234_5678_9abc_def0_u64);
+    let e = 0xfedc_ba98_7654_3210_u64;
+    let e_big = BigUint::from(e);
+
+    c.bench_function("fp pow 64-bit", |b| {
+        b.iter(|| black_box(&a).pow(black_box(&e_big)))
+    });
+    c.bench_function("fp pow_u64", |b| {
+        b.iter(|| black_box(&a).pow_u64(black_box(e)))
+    });
+    c.bench_function("fp inv", |b| b.iter(|| black_box(&a).inv()));
+    c.bench_function("fp sqrt", |b| b.iter(|| black_box(&a).sqrt()));
+}
+
+criterion_group!(benches, bench_mul, bench_mul_baseline, bench_pow);
+criterion_main!(benches);
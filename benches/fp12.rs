@@ -0,0 +1,35 @@
+use bn254::fp::Fp;
+use bn254::fp12::Fp12;
+use bn254::fp2::Fp2;
+use bn254::fp6::Fp6;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+
+/// An element of the cyclotomic subgroup, via the easy part of the final
+/// exponentiation
+fn cyclotomic_element() -> Fp12 {
+    let fp2 = |a: u32, b: u32| Fp2::new(Fp::new(a.into()), Fp::new(b.into()));
+    let f = Fp12::new(
+        Fp6::new(fp2(1, 2), fp2(3, 4), fp2(5, 6)),
+        Fp6::new(fp2(7, 8), fp2(9, 10), fp2(11, 12)),
+    );
+    let f1 = &f.frobenius_map(6) * &f.inv();
+    &f1.frobenius_map(2) * &f1
+}
+
+fn bench_square(c: &mut Criterion) {
+    let f = cyclotomic_element();
+    let two = BigUint::from(2u32);
+
+    c.bench_function("fp12 pow(2)", |b| b.iter(|| black_box(&f).pow(&two)));
+    c.bench_function("fp12 mul self", |b| {
+        b.iter(|| black_box(&f) * black_box(&f))
+    });
+    c.bench_function("fp12 square", |b| b.iter(|| black_box(&f).square()));
+    c.bench_function("fp12 cyclotomic_square", |b| {
+        b.iter(|| black_box(&f).cyclotomic_square())
+    });
+}
+
+criterion_group!(benches, bench_square);
+criterion_main!(benches);
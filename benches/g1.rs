@@ -0,0 +1,180 @@
+use bn254::g1::{G1Affine, G1Projective, G1};
+use bn254::scalar::Scalar;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+
+/// The group operations the two coordinate systems share, so the same
+/// double-and-add and bucket MSM can be timed on both
+trait Coords: Clone {
+    fn identity() -> Self;
+    fn dbl(&self) -> Self;
+    fn add_full(&self, other: &Self) -> Self;
+    fn add_mixed(&self, other: &G1Affine) -> Self;
+}
+
+impl Coords for G1 {
+    fn identity() -> Self {
+        G1::infinity()
+    }
+    fn dbl(&self) -> Self {
+        self.double()
+    }
+    fn add_full(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+    fn add_mixed(&self, other: &G1Affine) -> Self {
+        self.add_affine(other)
+    }
+}
+
+impl Coords for G1Projective {
+    fn identity() -> Self {
+        G1Projective::infinity()
+    }
+    fn dbl(&self) -> Self {
+        self.double()
+    }
+    fn add_full(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+    fn add_mixed(&self, other: &G1Affine) -> Self {
+        self.add_affine(other)
+    }
+}
+
+/// Left-to-right double-and-add with mixed additions of the affine base
+fn mul<P: Coords>(base: &G1Affine, scalar: &BigUint) -> P {
+    let mut acc = P::identity();
+    for i in (0..scalar.bits()).rev() {
+        acc = acc.dbl();
+        if scalar.bit(i) {
+            acc = acc.add_mixed(base);
+        }
+    }
+    acc
+}
+
+/// The bucket method of `g1::multi_scalar_mul` with 8-bit windows, adding
+/// affine bases into the buckets
+fn msm<P: Coords>(bases: &[G1Affine], scalars: &[BigUint]) -> P {
+    const C: u64 = 8;
+    let mut res = P::identity();
+    for w in (0..256u64.div_ceil(C)).rev() {
+        for _ in 0..C {
+            res = res.dbl();
+        }
+        let mut buckets = vec![P::identity(); (1 << C) - 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let digit = (0..C)
+                .filter(|&i| scalar.bit(w * C + i))
+                .fold(0usize, |acc, i| acc | 1 << i);
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].add_mixed(base);
+            }
+        }
+        let mut running = P::identity();
+        let mut window_sum = P::identity();
+        for bucket in buckets.iter().rev() {
+            running = running.add_full(bucket);
+            window_sum = window_sum.add_full(&running);
+        }
+        res = res.add_full(&window_sum);
+    }
+    res
+}
+
+fn scalar(i: u32) -> BigUint {
+    // A fixed full-width scalar, varied per index
+    BigUint::parse_bytes(
+        b"2c8a5f3e1d7b9a0f4e6c2b8d1a3f5e7c9b0d2f4a6c8e1b3d5f7a9c0e2b4d6f8",
+        16,
+    )
+    .unwrap()
+        * BigUint::from(i + 1)
+        % &*bn254::GROUP_ORDER
+}
+
+fn bench_coordinates(c: &mut Criterion) {
+    let g = G1::generator();
+    let base = G1Affine::from(g);
+    let k = scalar(0);
+    let (jac, proj) = (g, g.to_projective());
+
+    c.bench_function("g1 jacobian double", |b| {
+        b.iter(|| black_box(&jac).double())
+    });
+    c.bench_function("g1 projective double", |b| {
+        b.iter(|| black_box(&proj).double())
+    });
+    let (jac2, proj2) = (jac.double(), proj.double());
+    c.bench_function("g1 jacobian add", |b| {
+        b.iter(|| black_box(&jac).add(black_box(&jac2)))
+    });
+    c.bench_function("g1 projective add", |b| {
+        b.iter(|| black_box(&proj).add(black_box(&proj2)))
+    });
+    c.bench_function("g1 jacobian mul", |b| {
+        b.iter(|| mul::<G1>(black_box(&base), black_box(&k)))
+    });
+    c.bench_function("g1 projective mul", |b| {
+        b.iter(|| mul::<G1Projective>(black_box(&base), black_box(&k)))
+    });
+
+    let points: Vec<G1> = (1..=256u128).map(|i| g.mul_u128(i * 7919)).collect();
+    let bases: Vec<G1Affine> = points.into_iter().map(G1Affine::from).collect();
+    let scalars: Vec<BigUint> = (0..256).map(scalar).collect();
+    let mut group = c.benchmark_group("g1 msm 256");
+    group.sample_size(10);
+    group.bench_function("jacobian", |b| {
+        b.iter(|| msm::<G1>(black_box(&bases), black_box(&scalars)))
+    });
+    group.bench_function("projective", |b| {
+        b.iter(|| msm::<G1Projective>(black_box(&bases), black_box(&scalars)))
+    });
+    group.finish();
+}
+
+fn bench_mul_scalar(c: &mut Criterion) {
+    let g = G1::generator();
+    let k128 = BigUint::from(0x9a0f_4e6c_2b8d_1a3f_5e7c_9b0d_2f4a_6c8e_u128);
+    let k254 = &*bn254::GROUP_ORDER - 1u32;
+
+    c.bench_function("g1 mul_scalar 128-bit", |b| {
+        b.iter(|| black_box(&g).mul_scalar(black_box(&k128)))
+    });
+    c.bench_function("g1 mul_scalar 254-bit", |b| {
+        b.iter(|| black_box(&g).mul_scalar(black_box(&k254)))
+    });
+    c.bench_function("g1 naf_mul 254-bit", |b| {
+        b.iter(|| black_box(&g).naf_mul(black_box(&k254)))
+    });
+}
+
+fn bench_fixed_base(c: &mut Criterion) {
+    let g = G1::generator();
+    let k = scalar(0);
+    let table4 = g.precompute_fixed_base(4);
+    let table8 = g.precompute_fixed_base(8);
+
+    c.bench_function("g1 mul_scalar_glv", |b| {
+        b.iter(|| black_box(&g).mul_scalar_glv(black_box(&k)))
+    });
+    c.bench_function("g1 fixed base w=4", |b| {
+        b.iter(|| black_box(&table4).mul(black_box(&k)))
+    });
+    c.bench_function("g1 fixed base w=8", |b| {
+        b.iter(|| black_box(&table8).mul(black_box(&k)))
+    });
+
+    let s = Scalar::from(k.clone());
+    G1::mul_gen(&s);
+    c.bench_function("g1 mul_gen", |b| b.iter(|| G1::mul_gen(black_box(&s))));
+}
+
+criterion_group!(
+    benches,
+    bench_coordinates,
+    bench_mul_scalar,
+    bench_fixed_base
+);
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+use bn254::g2::G2;
+use bn254::scalar::Scalar;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use num_bigint::BigUint;
+
+fn bench_g2(c: &mut Criterion) {
+    let g = G2::generator();
+    let g2 = g.double();
+    // r - 1, a full 254-bit scalar
+    let k = BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088548364400416034343698204186575808495616",
+        10,
+    )
+    .unwrap();
+
+    c.bench_function("g2 double", |b| b.iter(|| black_box(&g).double()));
+    c.bench_function("g2 add", |b| b.iter(|| black_box(&g).add(black_box(&g2))));
+    c.bench_function("g2 mul_scalar", |b| {
+        b.iter(|| black_box(&g).mul_scalar(black_box(&k)))
+    });
+    c.bench_function("g2 mul_scalar_wnaf", |b| {
+        b.iter(|| black_box(&g).mul_scalar_wnaf(black_box(&k), 5))
+    });
+    c.bench_function("g2 mul_scalar_endo", |b| {
+        b.iter(|| black_box(&g).mul_scalar_endo(black_box(&k)))
+    });
+}
+
+fn bench_mul_gen(c: &mut Criterion) {
+    let g = G2::generator();
+    let k = &*bn254::GROUP_ORDER - 1u32;
+    let scalars: Vec<Scalar> = (1..=1000u32)
+        .map(|i| Scalar::from(&k * BigUint::from(i * 7919)))
+        .collect();
+    G2::mul_gen(&scalars[0]);
+
+    // A stream of products of the generator, with and without the table
+    let mut group = c.benchmark_group("g2 generator x1000");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(scalars.len() as u64));
+    group.bench_function("mul_scalar", |b| {
+        b.iter(|| {
+            for s in &scalars {
+                black_box(g.mul_scalar(s.as_biguint()));
+            }
+        })
+    });
+    group.bench_function("mul_gen", |b| {
+        b.iter(|| {
+            for s in &scalars {
+                black_box(G2::mul_gen(black_box(s)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_g2, bench_mul_gen);
+criterion_main!(benches);
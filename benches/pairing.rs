@@ -0,0 +1,39 @@
+use bn254::g1::G1;
+use bn254::g2::G2;
+use bn254::pairing::{miller_loop, multi_pairing, pairing};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_pairing(c: &mut Criterion) {
+    let p = G1::generator();
+    let q = G2::generator();
+    let f = miller_loop(&p, &q);
+
+    c.bench_function("miller_loop", |b| {
+        b.iter(|| miller_loop(black_box(&p), black_box(&q)))
+    });
+    c.bench_function("final_exponentiation", |b| {
+        b.iter(|| black_box(&f).clone().final_exp())
+    });
+    c.bench_function("pairing", |b| {
+        b.iter(|| pairing(black_box(&p), black_box(&q)))
+    });
+}
+
+fn bench_multi_pairing(c: &mut Criterion) {
+    let points: Vec<(G1, G2)> = (1..=64u128)
+        .map(|i| (G1::generator().mul_u128(i), G2::generator()))
+        .collect();
+    for n in [8, 64] {
+        let pairs: Vec<(&G1, &G2)> = points[..n].iter().map(|(p, q)| (p, q)).collect();
+        c.bench_function(&format!("multi_pairing {n}"), |b| {
+            b.iter(|| multi_pairing(black_box(&pairs)))
+        });
+        #[cfg(feature = "rayon")]
+        c.bench_function(&format!("par_miller_loop {n}"), |b| {
+            b.iter(|| bn254::pairing::par_miller_loop(black_box(&pairs)).final_exp())
+        });
+    }
+}
+
+criterion_group!(benches, bench_pairing, bench_multi_pairing);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+use bn254::fp::Fp;
+use bn254::fp12::Fp12;
+use bn254::fp2::Fp2;
+use bn254::fp6::Fp6;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn fp2(a: u32, b: u32) -> Fp2 {
+    Fp2::new(Fp::new(a.into()), Fp::new(b.into()))
+}
+
+fn fp6(k: u32) -> Fp6 {
+    Fp6::new(fp2(k, k + 1), fp2(k + 2, k + 3), fp2(k + 4, k + 5))
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let (a, b) = (fp2(1, 2), fp2(3, 4));
+    c.bench_function("fp2 mul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+
+    let (a, b) = (fp6(1), fp6(7));
+    c.bench_function("fp6 mul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+
+    let (a, b) = (Fp12::new(fp6(1), fp6(7)), Fp12::new(fp6(13), fp6(19)));
+    c.bench_function("fp12 mul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+}
+
+fn bench_square(c: &mut Criterion) {
+    let a = fp6(1);
+    c.bench_function("fp6 mul self", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&a))
+    });
+    c.bench_function("fp6 square", |bench| bench.iter(|| black_box(&a).square()));
+
+    let a = Fp12::new(fp6(1), fp6(7));
+    c.bench_function("fp12 mul self", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&a))
+    });
+    c.bench_function("fp12 square", |bench| bench.iter(|| black_box(&a).square()));
+}
+
+criterion_group!(benches, bench_mul, bench_square);
+criterion_main!(benches);
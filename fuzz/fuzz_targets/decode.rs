@@ -0,0 +1,28 @@
+//! Feeds arbitrary bytes to the field and point decoders. They must never
+//! panic, and anything they accept must survive an encode/decode roundtrip.
+#![no_main]
+
+use bn254::fp::Fp;
+use bn254::g1::G1Affine;
+use bn254::g2::G2Affine;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(bytes) = data.first_chunk::<32>() {
+        if let Ok(x) = Fp::from_bytes_be(bytes) {
+            assert_eq!(&x.to_bytes_be(), bytes);
+        }
+
+        if let Ok(p) = G1Affine::from_compressed(bytes) {
+            assert!(p.is_on_curve());
+            assert_eq!(G1Affine::from_compressed(&p.to_compressed()), Ok(p));
+        }
+    }
+
+    if let Some(bytes) = data.first_chunk::<64>() {
+        if let Ok(p) = G2Affine::from_compressed(bytes) {
+            assert!(p.is_on_curve());
+            assert_eq!(G2Affine::from_compressed(&p.to_compressed()), Ok(p));
+        }
+    }
+});
@@ -0,0 +1,25 @@
+//! Decodes a compressed G1 point (32 bytes) and a compressed G2 point
+//! (64 bytes) and pairs them. Inputs that fail to decode are skipped; the
+//! pairing itself must not panic, including on the points at infinity.
+#![no_main]
+
+use bn254::g1::{G1Affine, G1};
+use bn254::g2::{G2Affine, G2};
+use bn254::pairing::pairing;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: [u8; 96]| {
+    let (p, q) = data.split_at(32);
+    let (Ok(p), Ok(q)) = (
+        G1Affine::from_compressed(p.try_into().unwrap()),
+        G2Affine::from_compressed(q.try_into().unwrap()),
+    ) else {
+        return;
+    };
+
+    let (p, q) = (G1::from(p), G2::from(q));
+    let e = pairing(&p, &q);
+    if p.is_infinity() || q.is_infinity() {
+        assert!(e.is_identity());
+    }
+});
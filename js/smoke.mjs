@@ -0,0 +1,54 @@
+// Smoke test for the wasm-bindgen bindings. Build them first with
+//
+//   cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//   wasm-bindgen --target nodejs --out-dir pkg target/wasm32-unknown-unknown/release/bn254.wasm
+//
+// then run `node js/smoke.mjs` from the repository root, or run all three
+// steps with scripts/wasm-smoke.sh.
+
+import assert from "node:assert/strict";
+import { createRequire } from "node:module";
+
+const require = createRequire(import.meta.url);
+const { wasm_pairing, wasm_pairing_check, wasm_g1_mul } = require("../pkg/bn254.js");
+
+const hex = (s) => Uint8Array.from(Buffer.from(s, "hex"));
+const word = (n) => n.toString(16).padStart(64, "0");
+
+// EIP-196 / EIP-197 encodings of the generators and of -G1
+const g1 = hex(word(1n) + word(2n));
+const negG1 = hex(
+  word(1n) +
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"
+);
+const g2 = hex(
+  "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2" +
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed" +
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b" +
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+);
+
+// e(G1, G2) · e(-G1, G2) == 1
+assert.equal(wasm_pairing_check(new Uint8Array([...g1, ...g2, ...negG1, ...g2])), true);
+assert.equal(wasm_pairing_check(new Uint8Array([...g1, ...g2])), false);
+
+// e(G1, G2) is the textbook value, whose first coefficient matches py_ecc
+const e = wasm_pairing(g1, g2);
+assert.equal(e.length, 384);
+assert.equal(
+  Buffer.from(e.slice(0, 32)).toString("hex"),
+  "12c70e90e12b7874510cd1707e8856f71bf7f61d72631e268fca81000db9a1f5"
+);
+
+// [2]G1 == G1 + G1, here checked against the known coordinates of 2G
+const two = hex(word(2n));
+assert.deepEqual(
+  Buffer.from(wasm_g1_mul(g1, two)).toString("hex"),
+  "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3" +
+    "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"
+);
+
+// Malformed input throws
+assert.throws(() => wasm_pairing(g1.slice(0, 63), g2));
+
+console.log("wasm smoke test passed");
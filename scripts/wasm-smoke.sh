@@ -0,0 +1,31 @@
+#!/usr/bin/env sh
+# Builds the wasm-bindgen bindings of src/wasm.rs for Node.js into pkg/,
+# checks the generated TypeScript declarations and runs js/smoke.mjs.
+#
+# Needs the wasm32-unknown-unknown target, node, and the wasm-bindgen CLI
+# at the version in Cargo.lock, which the CLI insists on:
+#
+#   rustup target add wasm32-unknown-unknown
+#   cargo install wasm-bindgen-cli --version <version>
+
+set -eu
+cd "$(dirname "$0")/.."
+
+cargo build --target wasm32-unknown-unknown --features wasm
+cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+
+version=$(sed -n '/^name = "wasm-bindgen"$/{n;s/version = "\(.*\)"/\1/p;}' Cargo.lock)
+if ! wasm-bindgen --version | grep -q " $version\$"; then
+    echo "wasm-bindgen CLI $version required, found: $(wasm-bindgen --version)" >&2
+    exit 1
+fi
+wasm-bindgen --target nodejs --out-dir pkg target/wasm32-unknown-unknown/release/bn254.wasm
+
+for f in wasm_pairing wasm_pairing_check wasm_g1_mul; do
+    grep -q "export function $f(" pkg/bn254.d.ts || {
+        echo "pkg/bn254.d.ts does not declare $f" >&2
+        exit 1
+    }
+done
+
+node js/smoke.mjs
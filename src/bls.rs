@@ -0,0 +1,150 @@
+use crate::g1::{hash_to_g1, G1};
+use crate::g2::G2;
+use crate::pairing::{pairing, pairing_check};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// Domain separation tag used when hashing messages to G1
+pub const DST: &[u8] = b"BLS_SIG_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+
+/// Public key [sk]G2 for the secret scalar sk.
+///
+/// Secret keys are plain `BigUint`s, so the `zeroize` feature does not
+/// cover them; callers are responsible for wiping them.
+pub fn key_gen(sk: &BigUint) -> G2 {
+    G2::generator().mul_scalar(sk)
+}
+
+/// Signature [sk]H(msg), with H = `hash_to_g1` under `DST`. The secret
+/// key goes through the `mul_scalar` ladder.
+pub fn sign(sk: &BigUint, msg: &[u8]) -> G1 {
+    hash_to_g1(msg, DST).mul_scalar(sk)
+}
+
+/// Checks e(sig, G2) == e(H(msg), pk). The identity public key is
+/// rejected, as in the IETF KeyValidate: with pk = O and sig = O both
+/// sides are 1 for every message.
+pub fn verify(pk: &G2, msg: &[u8], sig: &G1) -> bool {
+    if !sig.is_on_curve() || !key_validate(pk) {
+        return false;
+    }
+    pairing(sig, &G2::generator()) == pairing(&hash_to_g1(msg, DST), pk)
+}
+
+/// Sums signatures into a single aggregate signature
+pub fn aggregate(sigs: &[G1]) -> G1 {
+    sigs.iter().fold(G1::infinity(), |acc, s| acc.add(s))
+}
+
+/// Verifies an aggregate signature over (pk, msg) pairs by checking
+/// e(-sig, G2) · ∏ e(H(msgᵢ), pkᵢ) == 1 with a single multi-pairing.
+/// Every public key must pass the same checks as in `verify`.
+///
+/// The messages must be distinct, otherwise this is open to rogue key
+/// attacks; pairs with a repeated message are rejected.
+pub fn aggregate_verify(pairs: &[(G2, &[u8])], sig: &G1) -> bool {
+    if pairs.is_empty() || !sig.is_on_curve() {
+        return false;
+    }
+    for (i, (pk, msg)) in pairs.iter().enumerate() {
+        if !key_validate(pk) {
+            return false;
+        }
+        if pairs[..i].iter().any(|(_, m)| m == msg) {
+            return false;
+        }
+    }
+
+    let neg_sig = -*sig;
+    let g2 = G2::generator();
+    let hashes: Vec<G1> = pairs.iter().map(|(_, msg)| hash_to_g1(msg, DST)).collect();
+
+    let mut terms = vec![(&neg_sig, &g2)];
+    terms.extend(hashes.iter().zip(pairs.iter().map(|(pk, _)| pk)));
+    pairing_check(&terms)
+}
+
+/// A public key must be a point of G2 other than the identity
+fn key_validate(pk: &G2) -> bool {
+    !pk.is_infinity() && pk.is_on_curve() && pk.is_in_subgroup()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret_key() -> BigUint {
+        BigUint::parse_bytes(
+            b"2a5f3c9d1e8b7a6f5e4d3c2b1a09f8e7d6c5b4a3928170f6e5d4c3b2a1908f7",
+            16,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sign_vector() {
+        // Computed with an independent Python implementation
+        let sig = sign(&secret_key(), b"hello world");
+        let (x, y) = sig.to_affine();
+        assert_eq!(
+            format!("{:x}", x),
+            "2eb6d2d6971cebfad8d5936d0ca41037319ceaaa83cb516ad2d7f538a8acb502"
+        );
+        assert_eq!(
+            format!("{:x}", y),
+            "2c2995ca53c46b05be1d2e962c915605729886f255cc9ba59c053635b7dfc523"
+        );
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let sk = secret_key();
+        let pk = key_gen(&sk);
+        let sig = sign(&sk, b"hello world");
+        assert!(verify(&pk, b"hello world", &sig));
+        assert!(!verify(&pk, b"goodbye world", &sig));
+        assert!(!verify(
+            &key_gen(&BigUint::from(7u32)),
+            b"hello world",
+            &sig
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_verify() {
+        let keys: Vec<BigUint> = (1u32..=3).map(|i| secret_key() + i).collect();
+        let msgs: [&[u8]; 3] = [b"one", b"two", b"three"];
+        let sig = aggregate(
+            &keys
+                .iter()
+                .zip(msgs.iter())
+                .map(|(sk, m)| sign(sk, m))
+                .collect::<Vec<_>>(),
+        );
+        let pairs: Vec<(G2, &[u8])> = keys.iter().map(key_gen).zip(msgs).collect();
+        assert!(aggregate_verify(&pairs, &sig));
+
+        let mut swapped = pairs.clone();
+        swapped.swap(0, 1);
+        swapped[0].1 = b"one";
+        swapped[1].1 = b"two";
+        assert!(!aggregate_verify(&swapped, &sig));
+
+        let repeated = vec![pairs[0].clone(), pairs[0].clone()];
+        assert!(!aggregate_verify(&repeated, &sig));
+
+        let mut with_identity = pairs.clone();
+        with_identity.push((G2::infinity(), b"four"));
+        assert!(!aggregate_verify(&with_identity, &sig));
+    }
+
+    #[test]
+    fn test_identity_key() {
+        // e(O, G2) == e(H(m), O) holds for every m, so the pair must be
+        // rejected up front
+        assert!(!verify(&G2::infinity(), b"any message", &G1::infinity()));
+        let pairs: Vec<(G2, &[u8])> = vec![(G2::infinity(), b"any message")];
+        assert!(!aggregate_verify(&pairs, &G1::infinity()));
+    }
+}
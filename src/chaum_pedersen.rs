@@ -0,0 +1,135 @@
+use crate::g1::{G1Affine, G1};
+use crate::hash_to_field::expand_message_xmd;
+use crate::scalar::Scalar;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// Domain separation tag of the Fiat-Shamir challenge
+pub const DST: &[u8] = b"BN254G1_CHAUM_PEDERSEN_XMD:SHA-256_CHALLENGE_";
+
+/// Domain separation tag of the deterministic nonce
+const NONCE_DST: &[u8] = b"BN254G1_CHAUM_PEDERSEN_XMD:SHA-256_NONCE_";
+
+/// Non-interactive proof that log_G(P) = log_H(Q), see `prove`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub challenge: Scalar,
+    pub response: Scalar,
+}
+
+/// Proves knowledge of x with P = [x]G and Q = [x]H without revealing it.
+///
+/// The prover commits to A = [k]G and B = [k]H, takes the challenge
+/// c = H(G, H, P, Q, A, B) and answers s = k - c·x, so that A = [s]G + [c]P
+/// and B = [s]H + [c]Q. The nonce k is derived from x and the statement
+/// rather than drawn at random, in the spirit of RFC 6979: reusing k for
+/// two different challenges would reveal x, and a deterministic k can
+/// only repeat for the same statement, where it yields the same proof.
+///
+/// G and H should be independent generators of G1, as for
+/// `pedersen::Parameters`.
+pub fn prove(x: &Scalar, g: &G1, h: &G1) -> Proof {
+    let p = g * x;
+    let q = h * x;
+    let statement = transcript(&[g, h, &p, &q]);
+
+    let mut nonce_input = x.to_bytes_be().to_vec();
+    nonce_input.extend_from_slice(&statement);
+    let k = hash_to_scalar(&nonce_input, NONCE_DST);
+
+    let challenge = challenge(&statement, &(g * &k), &(h * &k));
+    let response = &k - &(&challenge * x);
+    Proof {
+        challenge,
+        response,
+    }
+}
+
+/// Checks a proof that log_G(P) = log_H(Q) by recomputing the commitments
+/// A = [s]G + [c]P and B = [s]H + [c]Q and the challenge they hash to.
+/// All four points must be on the curve.
+pub fn verify(g: &G1, h: &G1, p: &G1, q: &G1, proof: &Proof) -> bool {
+    if ![g, h, p, q].iter().all(|point| point.is_on_curve()) {
+        return false;
+    }
+    let (c, s) = (&proof.challenge, &proof.response);
+    let a = (g * s).add(&(p * c));
+    let b = (h * s).add(&(q * c));
+    challenge(&transcript(&[g, h, p, q]), &a, &b) == *c
+}
+
+/// The 64-byte uncompressed encodings of the points, concatenated
+fn transcript(points: &[&G1]) -> Vec<u8> {
+    points
+        .iter()
+        .flat_map(|point| G1Affine::from(**point).to_uncompressed())
+        .collect()
+}
+
+fn challenge(statement: &[u8], a: &G1, b: &G1) -> Scalar {
+    let mut msg = statement.to_vec();
+    msg.extend(transcript(&[a, b]));
+    hash_to_scalar(&msg, DST)
+}
+
+/// 48 bytes of `expand_message_xmd` reduced mod r, as `hash_to_fp` does
+/// mod p, so the bias is below 2^-128
+fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Scalar {
+    Scalar::from(BigUint::from_bytes_be(&expand_message_xmd(msg, dst, 48)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::g1::hash_to_g1;
+
+    fn generators() -> (G1, G1) {
+        (
+            G1::generator(),
+            hash_to_g1(b"chaum-pedersen h", b"BN254-CHAUM-PEDERSEN-TEST"),
+        )
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let (g, h) = generators();
+        let x = Scalar::from(0x1234_5678_9abc_def0_u64);
+        let (p, q) = (&g * &x, &h * &x);
+
+        let proof = prove(&x, &g, &h);
+        assert!(verify(&g, &h, &p, &q, &proof));
+        // Deterministic nonce: the same statement gives the same proof
+        assert_eq!(prove(&x, &g, &h), proof);
+        // The statement is bound to the proof
+        assert!(!verify(&h, &g, &q, &p, &proof));
+    }
+
+    #[test]
+    fn test_forged_proof() {
+        let (g, h) = generators();
+        let x = Scalar::from(42u64);
+        let y = Scalar::from(43u64);
+        let (p, q) = (&g * &x, &h * &x);
+
+        // Unequal logarithms: a proof for x does not cover Q' = [y]H
+        let proof = prove(&x, &g, &h);
+        assert!(!verify(&g, &h, &p, &(&h * &y), &proof));
+
+        // Tampered responses and challenges
+        let mut forged = proof.clone();
+        forged.response = &forged.response + &Scalar::one();
+        assert!(!verify(&g, &h, &p, &q, &forged));
+        let mut forged = proof;
+        forged.challenge = &forged.challenge + &Scalar::one();
+        assert!(!verify(&g, &h, &p, &q, &forged));
+
+        // Choosing s first and solving for A, B does not match the hash
+        let s = Scalar::from(7u64);
+        let c = Scalar::from(9u64);
+        let forged = Proof {
+            challenge: c,
+            response: s,
+        };
+        assert!(!verify(&g, &h, &p, &q, &forged));
+    }
+}
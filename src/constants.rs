@@ -0,0 +1,140 @@
+//! Precomputed constants of the extension tower and the sextic twist: the
+//! Frobenius coefficients of Fp6 and Fp12 and the coefficients of the
+//! untwist-Frobenius-twist endomorphism ψ on G2. All of them are powers of
+//! ξ = u + 9, the non-residue defining Fp6 and the twist.
+
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+
+/// ξ^((p^k - 1) / 3) for k = 0..6, where ξ = u+9
+pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
+    fp2(b"1", b"0"),
+    fp2(
+        b"21575463638280843010398324269430826099269044274347216827212613867836435027261",
+        b"10307601595873709700152284273816112264069230130616436755625194854815875713954",
+    ),
+    fp2(
+        b"21888242871839275220042445260109153167277707414472061641714758635765020556616",
+        b"0",
+    ),
+    fp2(
+        b"3772000881919853776433695186713858239009073593817195771773381919316419345261",
+        b"2236595495967245188281701248203181795121068902605861227855261137820944008926",
+    ),
+    fp2(
+        b"2203960485148121921418603742825762020974279258880205651966",
+        b"0",
+    ),
+    fp2(
+        b"18429021223477853657660792034369865839114504446431234726392080002137598044644",
+        b"9344045779998320333812420223237981029506012124075525679208581902008406485703",
+    ),
+];
+
+/// ξ^(2(p^k - 1) / 3) for k = 0..6
+pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
+    fp2(b"1", b"0"),
+    fp2(
+        b"2581911344467009335267311115468803099551665605076196740867805258568234346338",
+        b"19937756971775647987995932169929341994314640652964949448313374472400716661030",
+    ),
+    fp2(
+        b"2203960485148121921418603742825762020974279258880205651966",
+        b"0",
+    ),
+    fp2(
+        b"5324479202449903542726783395506214481928257762400643279780343368557297135718",
+        b"16208900380737693084919495127334387981393726419856888799917914180988844123039",
+    ),
+    fp2(
+        b"21888242871839275220042445260109153167277707414472061641714758635765020556616",
+        b"0",
+    ),
+    fp2(
+        b"13981852324922362344252311234282257507216387789820983642040889267519694726527",
+        b"7629828391165209371577384193250820201684255241773809077146787135900891633097",
+    ),
+];
+
+/// ξ^((p^k - 1) / 6) for k = 0..12, where ξ = u+9
+pub(crate) const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
+    fp2(b"1", b"0"),
+    fp2(
+        b"8376118865763821496583973867626364092589906065868298776909617916018768340080",
+        b"16469823323077808223889137241176536799009286646108169935659301613961712198316",
+    ),
+    fp2(
+        b"21888242871839275220042445260109153167277707414472061641714758635765020556617",
+        b"0",
+    ),
+    fp2(
+        b"11697423496358154304825782922584725312912383441159505038794027105778954184319",
+        b"303847389135065887422783454877609941456349188919719272345083954437860409601",
+    ),
+    fp2(
+        b"21888242871839275220042445260109153167277707414472061641714758635765020556616",
+        b"0",
+    ),
+    fp2(
+        b"3321304630594332808241809054958361220322477375291206261884409189760185844239",
+        b"5722266937896532885780051958958348231143373700109372999374820235121374419868",
+    ),
+    fp2(
+        b"21888242871839275222246405745257275088696311157297823662689037894645226208582",
+        b"0",
+    ),
+    fp2(
+        b"13512124006075453725662431877630910996106405091429524885779419978626457868503",
+        b"5418419548761466998357268504080738289687024511189653727029736280683514010267",
+    ),
+    fp2(
+        b"2203960485148121921418603742825762020974279258880205651966",
+        b"0",
+    ),
+    fp2(
+        b"10190819375481120917420622822672549775783927716138318623895010788866272024264",
+        b"21584395482704209334823622290379665147239961968378104390343953940207365798982",
+    ),
+    fp2(
+        b"2203960485148121921418603742825762020974279258880205651967",
+        b"0",
+    ),
+    fp2(
+        b"18566938241244942414004596690298913868373833782006617400804628704885040364344",
+        b"16165975933942742336466353786298926857552937457188450663314217659523851788715",
+    ),
+];
+
+/// ξ^((p-1)/3) and ξ^((p-1)/2), with ξ = u + 9, scaling the x and y
+/// coordinates in the untwist-Frobenius-twist endomorphism ψ
+pub(crate) const PSI_COEFF_X: Fp2 = fp2(
+    b"21575463638280843010398324269430826099269044274347216827212613867836435027261",
+    b"10307601595873709700152284273816112264069230130616436755625194854815875713954",
+);
+pub(crate) const PSI_COEFF_Y: Fp2 = fp2(
+    b"2821565182194536844548159561693502659359617185244120367078079554186484126554",
+    b"3505843767911556378687030309984248845540243509899259641013678093033130930403",
+);
+
+/// Builds an Fp2 constant from the decimal strings of its coefficients
+pub(crate) const fn fp2(c0: &[u8], c1: &[u8]) -> Fp2 {
+    Fp2::new(Fp::from_dec(c0), Fp::from_dec(c1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_psi_coefficients() {
+        // ξ^((p-1)/3) cubed and ξ^((p-1)/2) squared are both ξ^(p-1),
+        // which is conj(ξ) / ξ because the p-power Frobenius of Fp2 is
+        // conjugation
+        let xi = fp2(b"9", b"1");
+        let x = &PSI_COEFF_X;
+        let y = &PSI_COEFF_Y;
+        assert_eq!(&(x * x) * x, y * y);
+        assert_eq!(&(y * y) * &xi, xi.conjugate());
+        assert_eq!(*x, FROBENIUS_COEFF_FP6_C1[1]);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::g1::{DecodeError, G1Affine, G1};
+use core::fmt;
+use num_bigint::BigUint;
+
+/// Errors returned by the EIP-196 precompiles. The EVM treats any of them
+/// as a failed call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Eip196Error {
+    /// A coordinate is not below the field modulus
+    InvalidFieldElement,
+    /// The point is neither (0, 0) nor on the curve
+    NotOnCurve,
+}
+
+impl fmt::Display for Eip196Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Eip196Error::InvalidFieldElement => "coordinate is not below the field modulus",
+            Eip196Error::NotOnCurve => "point is not on the curve",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip196Error {}
+
+/// Copies the input into a fixed-size buffer, padding with zeros on the
+/// right and ignoring anything past `N` bytes as the precompiles do
+fn pad<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut out = [0u8; N];
+    let len = input.len().min(N);
+    out[..len].copy_from_slice(&input[..len]);
+    out
+}
+
+/// Reads a 64-byte x || y point, with (0, 0) as the point at infinity
+fn read_point(bytes: &[u8]) -> Result<G1, Eip196Error> {
+    let p = G1Affine::from_uncompressed(bytes.try_into().unwrap()).map_err(|e| match e {
+        DecodeError::InvalidFieldElement => Eip196Error::InvalidFieldElement,
+        _ => Eip196Error::NotOnCurve,
+    })?;
+    Ok(p.into())
+}
+
+fn write_point(p: G1) -> [u8; 64] {
+    G1Affine::from(p).to_uncompressed()
+}
+
+/// The `ecAdd` precompile (address 0x06): the input is two points
+/// x1 || y1 || x2 || y2, each coordinate 32 bytes big-endian, and the
+/// output is their sum in the same encoding
+pub fn ec_add(input: &[u8]) -> Result<[u8; 64], Eip196Error> {
+    let input = pad::<128>(input);
+    let p = read_point(&input[..64])?;
+    let q = read_point(&input[64..])?;
+    Ok(write_point(p.add(&q)))
+}
+
+/// The `ecMul` precompile (address 0x07): the input is a point x || y
+/// followed by a 32-byte big-endian scalar, which is not reduced mod r
+pub fn ec_mul(input: &[u8]) -> Result<[u8; 64], Eip196Error> {
+    let input = pad::<96>(input);
+    let p = read_point(&input[..64])?;
+    let k = BigUint::from_bytes_be(&input[64..]);
+    Ok(write_point(p.mul_scalar(&k)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> alloc::vec::Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Vectors from the go-ethereum precompile test suite (bn256Add.json and
+    // bn256ScalarMul.json)
+    const G: &str = "0000000000000000000000000000000000000000000000000000000000000001\
+                     0000000000000000000000000000000000000000000000000000000000000002";
+    const G_DOUBLE: &str = "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+                            15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4";
+
+    #[test]
+    fn test_ec_add() {
+        let out = ec_add(&hex(&[G, G].concat())).unwrap();
+        assert_eq!(out.to_vec(), hex(G_DOUBLE));
+
+        let input = hex(
+            "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+             063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266\
+             07c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed\
+             06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7",
+        );
+        let expected = hex(
+            "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+             301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915",
+        );
+        assert_eq!(ec_add(&input).unwrap().to_vec(), expected);
+
+        // Missing bytes are zeros, so empty input adds infinity to itself
+        assert_eq!(ec_add(&[]).unwrap(), [0u8; 64]);
+        // Bytes past the two points are ignored
+        let mut long = hex(&[G, G].concat());
+        long.extend_from_slice(&[0xff; 10]);
+        assert_eq!(ec_add(&long).unwrap().to_vec(), hex(G_DOUBLE));
+    }
+
+    #[test]
+    fn test_ec_mul() {
+        let input = hex(
+            "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7\
+             21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204\
+             00000000000000000000000000000000000000000000000011138ce750fa15c2",
+        );
+        let expected = hex(
+            "070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c\
+             031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc",
+        );
+        assert_eq!(ec_mul(&input).unwrap().to_vec(), expected);
+
+        let two = "0000000000000000000000000000000000000000000000000000000000000002";
+        assert_eq!(
+            ec_mul(&hex(&[G, two].concat())).unwrap().to_vec(),
+            hex(G_DOUBLE)
+        );
+        // A scalar of zero, supplied by truncating the input
+        assert_eq!(ec_mul(&hex(G)).unwrap(), [0u8; 64]);
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let off_curve = "0000000000000000000000000000000000000000000000000000000000000001\
+                         0000000000000000000000000000000000000000000000000000000000000003";
+        assert_eq!(
+            ec_add(&hex(&[G, off_curve].concat())),
+            Err(Eip196Error::NotOnCurve)
+        );
+        assert_eq!(ec_mul(&hex(off_curve)), Err(Eip196Error::NotOnCurve));
+
+        let p = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47\
+                 0000000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(ec_add(&hex(p)), Err(Eip196Error::InvalidFieldElement));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::g1::{DecodeError, G1Affine, G1};
+use crate::g2::{G2Affine, G2};
+use crate::pairing::pairing_check;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Length of one (G1, G2) pair in the `ecPairing` input
+const PAIR_LEN: usize = 192;
+
+/// Errors returned by the EIP-197 precompile. The EVM treats any of them as
+/// a failed call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Eip197Error {
+    /// The input length is not a multiple of 192 bytes
+    InvalidLength,
+    /// A coordinate is not below the field modulus
+    InvalidFieldElement,
+    /// A point is neither all zeros nor on its curve
+    NotOnCurve,
+    /// A G2 point is not in the prime-order subgroup
+    NotInSubgroup,
+}
+
+impl fmt::Display for Eip197Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Eip197Error::InvalidLength => "input length is not a multiple of 192",
+            Eip197Error::InvalidFieldElement => "coordinate is not below the field modulus",
+            Eip197Error::NotOnCurve => "point is not on the curve",
+            Eip197Error::NotInSubgroup => "point is not in the prime-order subgroup",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip197Error {}
+
+impl From<DecodeError> for Eip197Error {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::InvalidFieldElement => Eip197Error::InvalidFieldElement,
+            DecodeError::NotInSubgroup => Eip197Error::NotInSubgroup,
+            _ => Eip197Error::NotOnCurve,
+        }
+    }
+}
+
+/// The `ecPairing` precompile (address 0x08). The input is a sequence of
+/// 192-byte pairs: a G1 point x || y followed by a G2 point
+/// x.c1 || x.c0 || y.c1 || y.c0, every value 32 bytes big-endian and the
+/// points at infinity encoded as zeros. The output is the 32-byte word 1
+/// if ∏ e(Pᵢ, Qᵢ) == 1 and 0 otherwise; empty input yields 1.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], Eip197Error> {
+    if !input.len().is_multiple_of(PAIR_LEN) {
+        return Err(Eip197Error::InvalidLength);
+    }
+
+    let points = input
+        .chunks(PAIR_LEN)
+        .map(|chunk| {
+            let p = G1Affine::from_uncompressed(chunk[..64].try_into().unwrap())?;
+            let q = G2Affine::from_uncompressed(chunk[64..].try_into().unwrap())?;
+            Ok((G1::from(p), G2::from(q)))
+        })
+        .collect::<Result<Vec<_>, Eip197Error>>()?;
+    let pairs: Vec<_> = points.iter().map(|(p, q)| (p, q)).collect();
+
+    let mut out = [0u8; 32];
+    out[31] = pairing_check(&pairs) as u8;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // The generators in precompile encoding, as listed in EIP-197
+    const G1_GEN: &str = "0000000000000000000000000000000000000000000000000000000000000001\
+                          0000000000000000000000000000000000000000000000000000000000000002";
+    const G2_GEN: &str = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+                          1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+                          090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+                          12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+
+    fn encode(p: &G1, q: &G2) -> Vec<u8> {
+        let mut out = G1Affine::from(*p).to_uncompressed().to_vec();
+        out.extend_from_slice(&G2Affine::from(q.clone()).to_uncompressed());
+        out
+    }
+
+    fn word(b: u8) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out[31] = b;
+        out
+    }
+
+    #[test]
+    fn test_generator_encoding() {
+        let g = encode(&G1::generator(), &G2::generator());
+        assert_eq!(g, hex(&[G1_GEN, G2_GEN].concat()));
+    }
+
+    #[test]
+    fn test_ec_pairing() {
+        assert_eq!(ec_pairing(&[]), Ok(word(1)));
+        assert_eq!(ec_pairing(&hex(&[G1_GEN, G2_GEN].concat())), Ok(word(0)));
+
+        // e(G1, G2) · e(-G1, G2) == 1
+        let mut input = encode(&G1::generator(), &G2::generator());
+        input.extend(encode(&-G1::generator(), &G2::generator()));
+        assert_eq!(ec_pairing(&input), Ok(word(1)));
+
+        // Pairs with a point at infinity contribute 1
+        assert_eq!(ec_pairing(&vec![0u8; 2 * PAIR_LEN]), Ok(word(1)));
+    }
+
+    #[test]
+    fn test_ec_pairing_bilinear() {
+        // e(2·G1, G2) · e(-G1, 2·G2) == 1
+        let two = 2u32.into();
+        let mut input = encode(&G1::generator().mul_scalar(&two), &G2::generator());
+        input.extend(encode(&-G1::generator(), &G2::generator().mul_scalar(&two)));
+        assert_eq!(ec_pairing(&input), Ok(word(1)));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(ec_pairing(&[0u8; 100]), Err(Eip197Error::InvalidLength));
+
+        let mut input = hex(&[G1_GEN, G2_GEN].concat());
+        input[63] = 3;
+        assert_eq!(ec_pairing(&input), Err(Eip197Error::NotOnCurve));
+
+        let mut input = hex(&[G1_GEN, G2_GEN].concat());
+        input[64..96].fill(0xff);
+        assert_eq!(ec_pairing(&input), Err(Eip197Error::InvalidFieldElement));
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "alloc")]
+use crate::eip196::Eip196Error;
+#[cfg(feature = "alloc")]
+use crate::eip197::Eip197Error;
+use crate::fp::{FpError, ParseFpError};
+use crate::g1::{DecodeError, PointError};
+use core::fmt;
+
+/// Crate-wide error type. Each module keeps its own detailed error enum
+/// (`DecodeError`, `PointError`, `FpError`, ...); all of them convert into
+/// this one, so code handling several can use `?` with a single type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Inversion of a zero field element
+    ZeroInverse,
+    /// The coordinates do not satisfy the curve equation
+    NotOnCurve,
+    /// The point is not in the prime-order subgroup
+    NotInSubgroup,
+    /// Malformed bytes or strings: bad length, flags or digits, or a
+    /// coordinate that is not below the field modulus
+    InvalidEncoding,
+    /// A scalar that must be reduced modulo r is not
+    ScalarOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Error::ZeroInverse => "called inv() on zero element",
+            Error::NotOnCurve => "point is not on the curve",
+            Error::NotInSubgroup => "point is not in the prime-order subgroup",
+            Error::InvalidEncoding => "invalid encoding",
+            Error::ScalarOutOfRange => "scalar is not below the group order",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<PointError> for Error {
+    fn from(e: PointError) -> Self {
+        match e {
+            PointError::NotOnCurve => Error::NotOnCurve,
+            PointError::NotInSubgroup => Error::NotInSubgroup,
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::NotOnCurve => Error::NotOnCurve,
+            DecodeError::NotInSubgroup => Error::NotInSubgroup,
+            DecodeError::InvalidCompressedFlag
+            | DecodeError::InvalidLength
+            | DecodeError::InvalidFieldElement => Error::InvalidEncoding,
+        }
+    }
+}
+
+impl From<FpError> for Error {
+    fn from(e: FpError) -> Self {
+        match e {
+            FpError::TooLarge | FpError::WrongLength => Error::InvalidEncoding,
+        }
+    }
+}
+
+impl From<ParseFpError> for Error {
+    fn from(_: ParseFpError) -> Self {
+        Error::InvalidEncoding
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Eip196Error> for Error {
+    fn from(e: Eip196Error) -> Self {
+        match e {
+            Eip196Error::InvalidFieldElement => Error::InvalidEncoding,
+            Eip196Error::NotOnCurve => Error::NotOnCurve,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Eip197Error> for Error {
+    fn from(e: Eip197Error) -> Self {
+        match e {
+            Eip197Error::InvalidLength | Eip197Error::InvalidFieldElement => Error::InvalidEncoding,
+            Eip197Error::NotOnCurve => Error::NotOnCurve,
+            Eip197Error::NotInSubgroup => Error::NotInSubgroup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use crate::fp12::Fp12;
+    use crate::fp2::Fp2;
+    use crate::fp6::Fp6;
+    use crate::g1::G1Affine;
+
+    fn decode(bytes: &[u8]) -> Result<G1Affine, Error> {
+        Ok(G1Affine::from_bytes(bytes)?)
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(decode(&[0u8; 5]), Err(Error::InvalidEncoding));
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        assert_eq!(decode(&bytes), Err(Error::NotOnCurve));
+        assert_eq!(Error::from(FpError::TooLarge), Error::InvalidEncoding);
+        assert_eq!(
+            Error::from(Eip197Error::NotInSubgroup),
+            Error::NotInSubgroup
+        );
+    }
+
+    #[test]
+    fn test_zero_inverse() {
+        assert_eq!(Fp::zero().try_inv(), Err(Error::ZeroInverse));
+        assert_eq!(Fp2::zero().try_inv(), Err(Error::ZeroInverse));
+        assert_eq!(Fp6::zero().try_inv(), Err(Error::ZeroInverse));
+        assert_eq!(Fp12::zero().try_inv(), Err(Error::ZeroInverse));
+        assert_eq!(Fp12::one().try_inv(), Ok(Fp12::one()));
+    }
+
+    #[test]
+    #[should_panic(expected = "called inv() on zero element")]
+    fn test_inv_zero_panics() {
+        Fp6::zero().inv();
+    }
+}
@@ -1,102 +1,883 @@
-use lazy_static::lazy_static;
-use num_bigint::{BigInt, BigUint, Sign};
-use num_traits::{One, Zero};
-use std::ops::{Add, Mul, Neg, Sub};
-
-lazy_static! {
-    static ref P: BigUint = BigUint::parse_bytes(
-        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+use crate::error::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use core::str::FromStr;
+#[cfg(feature = "alloc")]
+use num_bigint::BigUint;
+
+#[cfg(feature = "alloc")]
+lazy_static::lazy_static! {
+    /// The BN254 base field modulus p
+    pub(crate) static ref P: BigUint = BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088696311157297823662689037894645226208583",
         10
     )
     .unwrap();
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Fp {
-    pub n: BigUint,
+/// p as little-endian 64-bit limbs
+const MODULUS: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// p - 2, the inversion exponent, as little-endian 64-bit limbs
+const INV_EXP: [u64; 4] = [
+    0x3c208c16d87cfd45,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// (p + 1) / 4, the square root exponent since p ≡ 3 (mod 4)
+const SQRT_EXP: [u64; 4] = [
+    0x4f082305b61f3f52,
+    0x65e05aa45a1c72a3,
+    0x6e14116da0605617,
+    0x0c19139cb84c680a,
+];
+
+/// (p - 1) / 2, the exponent of Euler's criterion
+const LEGENDRE_EXP: [u64; 4] = [
+    0x9e10460b6c3e7ea3,
+    0xcbc0b548b438e546,
+    0xdc2822db40c0ac2e,
+    0x183227397098d014,
+];
+
+/// R = 2^256 mod p, the Montgomery form of one
+const R: [u64; 4] = [
+    0xd35d438dc58f0d9d,
+    0x0a78eb28f5c70b3d,
+    0x666ea36f7879462c,
+    0x0e0a77c19a07df2f,
+];
+
+/// R^2 = 2^512 mod p, used to convert into Montgomery form
+const R2: [u64; 4] = [
+    0xf32cfc5b538afa89,
+    0xb5e71911d44501fb,
+    0x47ab1eff0a417ff6,
+    0x06d89f71cab8351f,
+];
+
+/// R^3 = 2^768 mod p, used to bring the high half of a wide value into
+/// Montgomery form
+const R3: [u64; 4] = [
+    0xb1cd6dafda1530df,
+    0x62f210e6a7283db6,
+    0xef7f0b0c0ada0afb,
+    0x20fd6e902d592544,
+];
+
+/// -p^(-1) mod 2^64
+const INV: u64 = 0x87d20782e4866389;
+
+/// Computes a + b + carry, returning the result and the new carry
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes a - b - borrow, returning the result and the new borrow
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, (t >> 127) as u64)
+}
+
+/// Computes a + b * c + carry, returning the result and the new carry
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// A residue mod p held as a * 2^256 mod p in four little-endian limbs.
+/// The limbs are always fully reduced, so equality is limb equality.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct MontgomeryFp([u64; 4]);
+
+impl MontgomeryFp {
+    /// Subtracts p if the value (with an extra high limb) is not below p
+    #[inline(always)]
+    const fn reduce(limbs: [u64; 4], hi: u64) -> Self {
+        let (d0, b) = sbb(limbs[0], MODULUS[0], 0);
+        let (d1, b) = sbb(limbs[1], MODULUS[1], b);
+        let (d2, b) = sbb(limbs[2], MODULUS[2], b);
+        let (d3, b) = sbb(limbs[3], MODULUS[3], b);
+        let (_, b) = sbb(hi, 0, b);
+        if b == 0 {
+            MontgomeryFp([d0, d1, d2, d3])
+        } else {
+            MontgomeryFp(limbs)
+        }
+    }
+
+    /// Converts canonical limbs (below p) into Montgomery form
+    const fn to_montgomery(limbs: [u64; 4]) -> Self {
+        MontgomeryFp(limbs).mul(&MontgomeryFp(R2))
+    }
+
+    /// Converts back to canonical limbs
+    fn from_montgomery(a: &Self) -> [u64; 4] {
+        a.mul(&MontgomeryFp([1, 0, 0, 0])).0
+    }
+
+    #[inline]
+    fn add(&self, rhs: &Self) -> Self {
+        let (d0, c) = adc(self.0[0], rhs.0[0], 0);
+        let (d1, c) = adc(self.0[1], rhs.0[1], c);
+        let (d2, c) = adc(self.0[2], rhs.0[2], c);
+        let (d3, c) = adc(self.0[3], rhs.0[3], c);
+        Self::reduce([d0, d1, d2, d3], c)
+    }
+
+    #[inline]
+    fn sub(&self, rhs: &Self) -> Self {
+        let (d0, b) = sbb(self.0[0], rhs.0[0], 0);
+        let (d1, b) = sbb(self.0[1], rhs.0[1], b);
+        let (d2, b) = sbb(self.0[2], rhs.0[2], b);
+        let (d3, b) = sbb(self.0[3], rhs.0[3], b);
+        if b == 0 {
+            return MontgomeryFp([d0, d1, d2, d3]);
+        }
+        // Wrapped below zero: add p back
+        let (d0, c) = adc(d0, MODULUS[0], 0);
+        let (d1, c) = adc(d1, MODULUS[1], c);
+        let (d2, c) = adc(d2, MODULUS[2], c);
+        let (d3, _) = adc(d3, MODULUS[3], c);
+        MontgomeryFp([d0, d1, d2, d3])
+    }
+
+    /// Montgomery multiplication a * b * R^(-1) mod p using the CIOS method.
+    /// A `const fn` (hence the `while` loops) so that constants can be
+    /// brought into Montgomery form at compile time.
+    #[inline]
+    const fn mul(&self, rhs: &Self) -> Self {
+        let mut t = [0u64; 6];
+        let mut i = 0;
+        while i < 4 {
+            // t += a * b[i]
+            let mut carry = 0;
+            let mut j = 0;
+            while j < 4 {
+                let (v, c) = mac(t[j], self.0[j], rhs.0[i], carry);
+                t[j] = v;
+                carry = c;
+                j += 1;
+            }
+            let (v, c) = adc(t[4], carry, 0);
+            t[4] = v;
+            t[5] = c;
+
+            // t = (t + m * p) / 2^64, with m chosen so the low limb vanishes
+            let m = t[0].wrapping_mul(INV);
+            let (_, mut carry) = mac(t[0], m, MODULUS[0], 0);
+            let mut j = 1;
+            while j < 4 {
+                let (v, c) = mac(t[j], m, MODULUS[j], carry);
+                t[j - 1] = v;
+                carry = c;
+                j += 1;
+            }
+            let (v, c) = adc(t[4], carry, 0);
+            t[3] = v;
+            t[4] = t[5] + c;
+            i += 1;
+        }
+        Self::reduce([t[0], t[1], t[2], t[3]], t[4])
+    }
+
+    fn neg(&self) -> Self {
+        if self.is_zero() {
+            *self
+        } else {
+            MontgomeryFp(MODULUS).sub(self)
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+}
+
+/// All ones if `choice` is set, zero otherwise
+#[cfg(not(feature = "ct"))]
+#[inline]
+fn select_mask(choice: bool) -> u64 {
+    0u64.wrapping_sub(choice as u64)
 }
 
+/// All ones if `choice` is set, zero otherwise. `subtle::Choice` keeps the
+/// optimizer from turning the selection back into a branch.
+#[cfg(feature = "ct")]
+#[inline]
+fn select_mask(choice: bool) -> u64 {
+    use subtle::ConditionallySelectable;
+    u64::conditional_select(&0, &u64::MAX, subtle::Choice::from(choice as u8))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fp(MontgomeryFp);
+
 impl Fp {
+    /// The modulus p as little-endian 64-bit limbs
+    pub const MODULUS: [u64; 4] = MODULUS;
+
+    /// (p - 1) / 2 as little-endian 64-bit limbs, the exponent of Euler's
+    /// criterion
+    pub const P_MINUS_1_OVER_2: [u64; 4] = LEGENDRE_EXP;
+
+    /// (p + 1) / 4 as little-endian 64-bit limbs, the square root exponent
+    /// for p ≡ 3 (mod 4)
+    pub const P_PLUS_1_OVER_4: [u64; 4] = SQRT_EXP;
+
+    /// The additive identity
+    pub const ZERO: Fp = Fp(MontgomeryFp([0; 4]));
+
+    /// The multiplicative identity
+    pub const ONE: Fp = Fp(MontgomeryFp(R));
+
+    /// 3, a generator of the multiplicative group Fp*. p - 1 factors as
+    /// 2 · 3² · 13 · 29 · 67 · 229 · 311 · 983 · 11003 · 405928799 ·
+    /// 11465965001 · 13427688667394608761327070753331941386769, and
+    /// 3^((p-1)/q) ≠ 1 for each of those primes q, so 3 has order p - 1.
+    /// 2 does not qualify: p ≡ 7 mod 8 makes it a square.
+    pub const MULTIPLICATIVE_GENERATOR: Fp = Fp(MontgomeryFp([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ]));
+
+    /// -1, a quadratic non-residue since p ≡ 3 mod 4. This is why Fp2 can
+    /// be built as Fp[u] / (u² + 1), and why `sqrt` needs no Tonelli-Shanks
+    /// and so no non-residue of its own.
+    pub const NON_RESIDUE: Fp = Fp(MontgomeryFp([
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ]));
+
+    #[cfg(feature = "alloc")]
     pub fn new(n: BigUint) -> Self {
         let n = n % &*P;
-        Fp { n }
+        let mut limbs = [0u64; 4];
+        for (limb, digit) in limbs.iter_mut().zip(n.iter_u64_digits()) {
+            *limb = digit;
+        }
+        Fp(MontgomeryFp::to_montgomery(limbs))
     }
 
     pub fn zero() -> Self {
-        Fp { n: BigUint::zero() }
+        Fp::ZERO
     }
 
     pub fn one() -> Self {
-        Fp { n: BigUint::one() }
+        Fp::ONE
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// The decimal constant `digits`, in Montgomery form at compile time.
+    /// Panics (failing the build for a `const`) unless it is below p.
+    pub(crate) const fn from_dec(digits: &[u8]) -> Self {
+        match parse_limbs(digits, 10) {
+            Ok(limbs) => Fp(MontgomeryFp::to_montgomery(limbs)),
+            Err(_) => panic!("invalid field element constant"),
+        }
+    }
+
+    /// The canonical residue in [0, p)
+    #[cfg(feature = "alloc")]
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.to_bytes_le())
+    }
+
+    /// The canonical residue in [0, p), which used to be the public field
+    /// `n` before `Fp` moved to Montgomery form
+    #[deprecated(note = "use `to_biguint`")]
+    #[cfg(feature = "alloc")]
+    pub fn n(&self) -> BigUint {
+        self.to_biguint()
     }
 
+    /// Inverse via Fermat's little theorem: a^(p-2)
+    ///
+    /// Panics if `self` is zero; `try_inv` returns an error instead.
     pub fn inv(&self) -> Self {
-        let mut a = BigInt::from(self.n.clone());
-        let mut m = BigInt::from(P.clone());
-        let mut x0 = BigInt::zero();
-        let mut x1 = BigInt::one();
+        if self.is_zero() {
+            panic!("called inv() on zero element");
+        }
+        self.pow_vartime(&INV_EXP)
+    }
+
+    /// The inverse, or `Error::ZeroInverse` for zero
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.inv())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        self.pow_vartime(&exp.to_u64_digits())
+    }
+
+    /// self^exp for an exponent given as little-endian 64-bit limbs, by
+    /// square-and-multiply without allocating. The running time depends on
+    /// the exponent, so it must not be secret.
+    pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+        // Leading zero bits are skipped rather than squaring one
+        let mut res = MontgomeryFp(R);
+        let mut started = false;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                if started {
+                    res = res.mul(&res);
+                }
+                if (limb >> i) & 1 == 1 {
+                    res = res.mul(&self.0);
+                    started = true;
+                }
+            }
+        }
+        Fp(res)
+    }
+
+    /// self^exp for a single-limb exponent, see `pow_vartime`
+    pub fn pow_u64(&self, exp: u64) -> Self {
+        self.pow_vartime(&[exp])
+    }
 
-        if a.is_zero() {
-            panic!("Inverse does not exist for zero");
+    /// Returns `b` if `choice` is set and `a` otherwise. The limbs are
+    /// blended with a mask instead of branching on `choice`. With the `ct`
+    /// feature the same selection is public as
+    /// `subtle::ConditionallySelectable`.
+    pub(crate) fn select(a: &Fp, b: &Fp, choice: bool) -> Fp {
+        let mask = select_mask(choice);
+        let mut limbs = [0u64; 4];
+        for (l, (x, y)) in limbs.iter_mut().zip(a.0 .0.iter().zip(b.0 .0.iter())) {
+            *l = x ^ (mask & (x ^ y));
         }
+        Fp(MontgomeryFp(limbs))
+    }
 
-        while a != BigInt::one() {
-            let q = &a / &m;
-            let mut t = m.clone();
-            m = &a % &m;
-            a = t;
-            t = x0.clone();
-            x0 = &x1 - &q * &x0;
-            x1 = t;
+    /// [base⁰, base¹, ..., baseⁿ], using n multiplications
+    #[cfg(feature = "alloc")]
+    pub fn powers(base: &Fp, n: usize) -> Vec<Fp> {
+        let mut out = Vec::with_capacity(n + 1);
+        let mut acc = MontgomeryFp(R);
+        for _ in 0..n {
+            out.push(Fp(acc));
+            acc = acc.mul(&base.0);
         }
+        out.push(Fp(acc));
+        out
+    }
 
-        if x1.sign() == Sign::Minus {
-            x1 += BigInt::from(P.clone());
+    /// base^e for each exponent e. The squarings base^(2^i) are computed
+    /// once and shared, so each exponent only costs one multiplication per
+    /// set bit.
+    #[cfg(feature = "alloc")]
+    pub fn batch_pow(base: &Fp, exponents: &[BigUint]) -> Vec<Fp> {
+        let bits = exponents.iter().map(|e| e.bits()).max().unwrap_or(0);
+        let mut squares = Vec::with_capacity(bits as usize);
+        let mut sq = base.0;
+        for _ in 0..bits {
+            squares.push(sq);
+            sq = sq.mul(&sq);
         }
 
-        Fp::new(x1.to_biguint().unwrap())
+        exponents
+            .iter()
+            .map(|e| {
+                let mut res = MontgomeryFp(R);
+                for (i, s) in squares.iter().enumerate() {
+                    if e.bit(i as u64) {
+                        res = res.mul(s);
+                    }
+                }
+                Fp(res)
+            })
+            .collect()
     }
-    
-    pub fn pow(&self, exp: &BigUint) -> Self {
-        Fp::new(self.n.modpow(exp, &*P))
+
+    /// Square root, or None if self is not a square. Of the two roots, the
+    /// one with the smaller canonical residue is returned. See `sqrt_3mod4`.
+    pub fn sqrt(&self) -> Option<Self> {
+        self.sqrt_3mod4()
+    }
+
+    /// Square root for p ≡ 3 (mod 4): a root of a square x is x^((p+1)/4),
+    /// a single exponentiation, and squaring the candidate tells whether x
+    /// was a square at all. Returns the smaller of the two roots.
+    pub fn sqrt_3mod4(&self) -> Option<Self> {
+        let root = self.pow_vartime(&Self::P_PLUS_1_OVER_4);
+        if root * root != *self {
+            return None;
+        }
+        let neg = -root;
+        // Big-endian bytes compare in the same order as the integers
+        if neg.to_bytes_be() < root.to_bytes_be() {
+            Some(neg)
+        } else {
+            Some(root)
+        }
+    }
+
+    /// The Legendre symbol (x/p) by Euler's criterion: x^((p-1)/2) is 1 for
+    /// non-zero squares, p - 1 for non-squares and 0 for zero
+    pub fn legendre(&self) -> i32 {
+        let e = self.pow_vartime(&Self::P_MINUS_1_OVER_2);
+        if e.is_zero() {
+            0
+        } else if e == Fp::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Whether x is a square, i.e. its Legendre symbol is not -1. Zero
+    /// counts as a square.
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// The "sign" of an element as defined in RFC 9380 section 4.1: the
+    /// parity of its canonical residue
+    pub fn sgn0(&self) -> bool {
+        self.to_bytes_be()[31] & 1 == 1
+    }
+
+    /// A uniformly random element. 32 random bytes with the top two bits
+    /// cleared are drawn until they encode a value below p < 2^254, which
+    /// happens about three times in four.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] &= 0x3f;
+            if let Ok(x) = Fp::from_bytes_be(&bytes) {
+                return x;
+            }
+        }
+    }
+
+    /// Big-endian encoding of the canonical residue
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut out = self.to_bytes_le();
+        out.reverse();
+        out
+    }
+
+    /// Little-endian encoding of the canonical residue
+    pub fn to_bytes_le(&self) -> [u8; 32] {
+        let limbs = self.to_u64_le_digits();
+        let mut out = [0u8; 32];
+        for (chunk, limb) in out.chunks_mut(8).zip(limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        out
+    }
+
+    /// Reduces a 512-bit big-endian integer modulo p, without allocating.
+    /// Writing it as hi·2^256 + lo, each half is below 2^256 and so can be
+    /// Montgomery-multiplied by R² (for lo) or R³ (for hi, which also
+    /// absorbs the 2^256).
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        let limbs = |half: &[u8]| {
+            let mut out = [0u64; 4];
+            for (limb, chunk) in out.iter_mut().zip(half.rchunks(8)) {
+                *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+            }
+            out
+        };
+        let hi = MontgomeryFp(limbs(&bytes[..32])).mul(&MontgomeryFp(R3));
+        let lo = MontgomeryFp(limbs(&bytes[32..])).mul(&MontgomeryFp(R2));
+        Fp(hi.add(&lo))
+    }
+
+    /// Decodes exactly 32 big-endian bytes. Values not below p are rejected
+    /// with `FpError::TooLarge` rather than reduced.
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, FpError> {
+        let mut le: [u8; 32] = bytes.try_into().map_err(|_| FpError::WrongLength)?;
+        le.reverse();
+        Self::from_bytes_le(&le)
+    }
+
+    /// Decodes exactly 32 little-endian bytes, see `from_bytes_be`
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self, FpError> {
+        let bytes: &[u8; 32] = bytes.try_into().map_err(|_| FpError::WrongLength)?;
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self::from_u64_le_digits(limbs)
+    }
+
+    /// The canonical residue as little-endian 64-bit limbs, the layout
+    /// arkworks' `BigInt<4>` uses
+    pub fn to_u64_le_digits(&self) -> [u64; 4] {
+        MontgomeryFp::from_montgomery(&self.0)
+    }
+
+    /// From little-endian 64-bit limbs, rejecting values not below p
+    pub fn from_u64_le_digits(digits: [u64; 4]) -> Result<Self, FpError> {
+        if !below_modulus(&digits) {
+            return Err(FpError::TooLarge);
+        }
+        Ok(Fp(MontgomeryFp::to_montgomery(digits)))
+    }
+}
+
+/// Orders by the canonical residue in [0, p), so the order matches that
+/// of `to_biguint` and of the big-endian byte encodings. This is only for
+/// sorting and map keys; it has no meaning for the field arithmetic.
+impl Ord for Fp {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let a = self.to_u64_le_digits();
+        let b = other.to_u64_le_digits();
+        a.iter().rev().cmp(b.iter().rev())
+    }
+}
+
+impl PartialOrd for Fp {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The default is zero
+impl Default for Fp {
+    fn default() -> Self {
+        Fp::zero()
+    }
+}
+
+/// The canonical residue in decimal
+impl fmt::Display for Fp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.to_u64_le_digits();
+        // 78 digits hold any 256-bit value
+        let mut buf = [0u8; 78];
+        let mut pos = buf.len();
+        loop {
+            // Divide by 10 from the top limb down; the remainder is the digit
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let cur = (rem << 64) | *limb as u128;
+                *limb = (cur / 10) as u64;
+                rem = cur % 10;
+            }
+            pos -= 1;
+            buf[pos] = b'0' + rem as u8;
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+        f.pad_integral(true, "", core::str::from_utf8(&buf[pos..]).unwrap())
+    }
+}
+
+/// The canonical residue as 64 zero-padded hex digits, 0x-prefixed with `{:#x}`
+impl fmt::LowerHex for Fp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for b in self.to_bytes_be() {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Fp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fp")
+            .field("n", &format_args!("{}", self))
+            .finish()
+    }
+}
+
+#[cfg(feature = "ct")]
+impl subtle::ConstantTimeEq for Fp {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0 .0[..].ct_eq(&other.0 .0[..])
+    }
+}
+
+/// Selects limb by limb with `u64::conditional_select`
+#[cfg(feature = "ct")]
+impl subtle::ConditionallySelectable for Fp {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        let mut limbs = [0u64; 4];
+        for (l, (x, y)) in limbs.iter_mut().zip(a.0 .0.iter().zip(b.0 .0.iter())) {
+            *l = u64::conditional_select(x, y, choice);
+        }
+        Fp(MontgomeryFp(limbs))
+    }
+}
+
+/// Overwrites the limbs with zeros. `Fp` holds its value inline, so this
+/// wipes it completely, but copies made earlier (`Fp` is `Copy`) are
+/// not affected. Scalars kept as `BigUint` (such as BLS secret keys) live
+/// on the heap and cannot be wiped reliably through this crate.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fp {
+    fn zeroize(&mut self) {
+        self.0 .0.zeroize();
+    }
+}
+
+/// Inverts every element with a single field inversion (Montgomery's trick).
+/// Zero has no inverse and is mapped to zero.
+#[cfg(feature = "alloc")]
+pub fn batch_inv(values: &[Fp]) -> Vec<Fp> {
+    // prefix[i] = product of the non-zero values before index i
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fp::one();
+    for v in values {
+        prefix.push(acc);
+        if !v.is_zero() {
+            acc = acc * *v;
+        }
+    }
+
+    let mut inv = acc.inv();
+    let mut res = vec![Fp::zero(); values.len()];
+    for (i, v) in values.iter().enumerate().rev() {
+        if !v.is_zero() {
+            res[i] = inv * prefix[i];
+            inv = inv * *v;
+        }
+    }
+    res
+}
+
+/// Errors returned when parsing a field element from a string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFpError {
+    /// The string has no digits
+    Empty,
+    /// The string contains a character that is not a digit of its radix
+    InvalidDigit,
+    /// The value is not below the field modulus
+    TooLarge,
+    /// An Fp2 string does not have exactly two comma-separated components
+    InvalidComponentCount,
+}
+
+impl fmt::Display for ParseFpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ParseFpError::Empty => "cannot parse field element from empty string",
+            ParseFpError::InvalidDigit => "invalid digit found in field element string",
+            ParseFpError::TooLarge => "field element is not below the modulus",
+            ParseFpError::InvalidComponentCount => "expected two comma-separated components",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFpError {}
+
+/// Parses "0x"-prefixed hexadecimal (either case) or plain decimal.
+/// Values must be below p; no reduction is applied.
+impl FromStr for Fp {
+    type Err = ParseFpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => (hex, 16),
+            None => (s, 10),
+        };
+        let limbs = parse_limbs(digits.as_bytes(), radix)?;
+        Ok(Fp(MontgomeryFp::to_montgomery(limbs)))
+    }
+}
+
+/// The little-endian limbs of the integer with the given radix 10 or 16
+/// digits, which must be below p. A `const fn` for `Fp::from_dec`. All
+/// digits are checked before a value that is too large is reported.
+const fn parse_limbs(digits: &[u8], radix: u64) -> Result<[u64; 4], ParseFpError> {
+    if digits.is_empty() {
+        return Err(ParseFpError::Empty);
+    }
+    let mut limbs = [0u64; 4];
+    let mut overflow = false;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b @ b'0'..=b'9' => (b - b'0') as u64,
+            b @ b'a'..=b'f' if radix == 16 => (b - b'a' + 10) as u64,
+            b @ b'A'..=b'F' if radix == 16 => (b - b'A' + 10) as u64,
+            _ => return Err(ParseFpError::InvalidDigit),
+        };
+        // limbs = limbs * radix + digit
+        let mut carry = digit;
+        let mut j = 0;
+        while j < 4 {
+            let (v, c) = mac(0, limbs[j], radix, carry);
+            limbs[j] = v;
+            carry = c;
+            j += 1;
+        }
+        overflow |= carry != 0;
+        i += 1;
+    }
+    if overflow || !below_modulus(&limbs) {
+        return Err(ParseFpError::TooLarge);
+    }
+    Ok(limbs)
+}
+
+/// Whether little-endian limbs encode a value below p
+const fn below_modulus(limbs: &[u64; 4]) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if limbs[i] != MODULUS[i] {
+            return limbs[i] < MODULUS[i];
+        }
+    }
+    false
+}
+
+impl From<u32> for Fp {
+    fn from(n: u32) -> Self {
+        Fp::from(n as u128)
+    }
+}
+
+impl From<u64> for Fp {
+    fn from(n: u64) -> Self {
+        Fp::from(n as u128)
+    }
+}
+
+/// Every u128 is below p, so the limbs go straight into Montgomery form
+impl From<u128> for Fp {
+    fn from(n: u128) -> Self {
+        Fp(MontgomeryFp::to_montgomery([
+            n as u64,
+            (n >> 64) as u64,
+            0,
+            0,
+        ]))
+    }
+}
+
+/// Errors returned when converting bytes into a field element
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FpError {
+    /// The value is not below the field modulus
+    TooLarge,
+    /// The input is not 32 bytes long
+    WrongLength,
+}
+
+impl fmt::Display for FpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            FpError::TooLarge => "field element is not below the modulus",
+            FpError::WrongLength => "field element encoding is not 32 bytes",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FpError {}
+
+/// Reads the bytes as a big-endian integer of any length (32 bytes being
+/// the usual encoding). Values must be below p; no reduction is applied.
+impl TryFrom<&[u8]> for Fp {
+    type Error = FpError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+        if bytes.len() > 32 {
+            return Err(FpError::TooLarge);
+        }
+        let mut be = [0u8; 32];
+        be[32 - bytes.len()..].copy_from_slice(bytes);
+        Fp::from_bytes_be(&be)
+    }
+}
+
+/// Serialized as a 0x-prefixed, 64-digit big-endian hex string
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&alloc::format!("{:#x}", self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let s = alloc::string::String::deserialize(deserializer)?;
+        let digits = s
+            .strip_prefix("0x")
+            .ok_or_else(|| D::Error::custom("field element must start with 0x"))?;
+        if digits.len() > 64 {
+            return Err(D::Error::custom(
+                "field element has more than 64 hex digits",
+            ));
+        }
+        s.parse().map_err(D::Error::custom)
     }
 }
 
 // Operator overloading
 impl Add for Fp {
     type Output = Fp;
+    #[inline]
     fn add(self, rhs: Fp) -> Fp {
-        Fp::new(self.n + rhs.n)
+        Fp(self.0.add(&rhs.0))
     }
 }
 
 impl Sub for Fp {
     type Output = Fp;
+    #[inline]
     fn sub(self, rhs: Fp) -> Fp {
-        let res = if self.n >= rhs.n {
-            &self.n - &rhs.n
-        } else {
-            &self.n + &*P - &rhs.n
-        };
-        Fp::new(res)
+        Fp(self.0.sub(&rhs.0))
     }
 }
 
 impl Mul for Fp {
     type Output = Fp;
+    #[inline]
     fn mul(self, rhs: Fp) -> Fp {
-        Fp::new(self.n * rhs.n)
+        Fp(self.0.mul(&rhs.0))
     }
 }
 
 impl Neg for Fp {
     type Output = Fp;
     fn neg(self) -> Fp {
-        if self.n.is_zero() {
-            Fp::zero()
-        } else {
-            Fp::new(&*P - self.n)
-        }
+        Fp(self.0.neg())
     }
 }
 
@@ -104,6 +885,7 @@ impl Neg for Fp {
 mod tests {
     use super::*;
     use num_bigint::ToBigUint;
+    use num_traits::{One, Zero};
     use rand::Rng;
 
     #[test]
@@ -118,6 +900,14 @@ mod tests {
         }
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_n() {
+        let n = &*P - 5u32;
+        assert_eq!(Fp::new(n.clone()).n(), n);
+        assert_eq!(Fp::new(&*P + 7u32).n(), BigUint::from(7u32));
+    }
+
     #[test]
     fn test_fermat_little_theorem() {
         let mut rng = rand::thread_rng();
@@ -130,14 +920,416 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_legendre() {
+        assert_eq!(Fp::from(4u64).legendre(), 1);
+        assert_eq!(Fp::from(5u64).legendre(), -1);
+        assert_eq!(Fp::zero().legendre(), 0);
+        assert_eq!(Fp::NON_RESIDUE.legendre(), -1);
+        let x = Fp::from(123456789u64);
+        assert_eq!((x * x).legendre(), 1);
+    }
+
+    #[test]
+    fn test_multiplicative_generator() {
+        let g = Fp::MULTIPLICATIVE_GENERATOR;
+        assert_eq!(g, Fp::from(3u64));
+        assert_eq!(g.pow(&((&*P - 1u32) / 2u32)), -Fp::one());
+        let factors = [
+            "2",
+            "3",
+            "13",
+            "29",
+            "67",
+            "229",
+            "311",
+            "983",
+            "11003",
+            "405928799",
+            "11465965001",
+            "13427688667394608761327070753331941386769",
+        ];
+        let mut cofactor = &*P - 1u32;
+        for q in factors {
+            let q = BigUint::parse_bytes(q.as_bytes(), 10).unwrap();
+            while (&cofactor % &q).is_zero() {
+                cofactor /= &q;
+            }
+            assert_ne!(g.pow(&((&*P - 1u32) / &q)), Fp::one());
+        }
+        assert!(cofactor.is_one());
+
+        assert_eq!(Fp::NON_RESIDUE, -Fp::one());
+        assert!(!Fp::NON_RESIDUE.is_quadratic_residue());
+    }
+
+    #[test]
+    fn test_pow_vartime() {
+        let mut rng = rand::thread_rng();
+        let a = Fp::new(rng.gen::<u128>().into());
+        for exp in [0u64, 1, 2, 5, u64::MAX, rng.gen()] {
+            let expected = a.to_biguint().modpow(&exp.into(), &P);
+            assert_eq!(a.pow_u64(exp).to_biguint(), expected);
+            assert_eq!(a.pow(&exp.into()), a.pow_u64(exp));
+        }
+        let big = |l: &[u64]| {
+            BigUint::from_bytes_le(&l.iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>())
+        };
+        let limbs = [rng.gen(), rng.gen(), 0, rng.gen(), 0];
+        assert_eq!(
+            a.pow_vartime(&limbs).to_biguint(),
+            a.to_biguint().modpow(&big(&limbs), &P)
+        );
+        assert_eq!(a.pow_vartime(&[]), Fp::one());
+
+        // The fixed exponents agree with their definitions
+        assert_eq!(big(&INV_EXP), &*P - 2u32);
+        assert_eq!(big(&SQRT_EXP), (&*P + 1u32) >> 2);
+        assert_eq!(big(&LEGENDRE_EXP), (&*P - 1u32) >> 1);
+    }
+
     #[test]
     fn test_basic_add_sub_mul_neg() {
         let a = Fp::new(10u32.to_biguint().unwrap());
         let b = Fp::new(15u32.to_biguint().unwrap());
 
-        assert_eq!(a.clone() + b.clone(), Fp::new(25u32.to_biguint().unwrap()));
-        assert_eq!(b.clone() - a.clone(), Fp::new(5u32.to_biguint().unwrap()));
-        assert_eq!(a.clone() * b.clone(), Fp::new(150u32.to_biguint().unwrap()));
-        assert_eq!(-a.clone(), Fp::new(&*P - 10u32.to_biguint().unwrap()));
+        assert_eq!(a + b, Fp::new(25u32.to_biguint().unwrap()));
+        assert_eq!(b - a, Fp::new(5u32.to_biguint().unwrap()));
+        assert_eq!(a * b, Fp::new(150u32.to_biguint().unwrap()));
+        assert_eq!(-a, Fp::new(&*P - 10u32.to_biguint().unwrap()));
+    }
+    #[test]
+    fn test_sqrt() {
+        let two = Fp::new(2u32.to_biguint().unwrap());
+        let four = Fp::new(4u32.to_biguint().unwrap());
+        assert_eq!(four.sqrt(), Some(two));
+        assert_eq!(four.sqrt_3mod4(), Some(two));
+        assert_eq!(Fp::zero().sqrt_3mod4(), Some(Fp::zero()));
+        assert_eq!(Fp::one().sqrt_3mod4(), Some(Fp::one()));
+
+        // -1 is a non-residue since p ≡ 3 (mod 4)
+        let minus_one = -Fp::one();
+        assert!(!minus_one.is_quadratic_residue());
+        assert_eq!(minus_one.sqrt(), None);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = Fp::new(rng.gen::<u128>().to_biguint().unwrap());
+            let sq = a * a;
+            assert!(sq.is_quadratic_residue());
+            let root = sq.sqrt().unwrap();
+            assert!(root == a || root == -a);
+            assert!(root.to_biguint() <= (-root).to_biguint());
+
+            let non_residue = sq * minus_one;
+            assert_eq!(non_residue.is_quadratic_residue(), a.is_zero());
+            assert_eq!(non_residue.sqrt().is_some(), a.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_batch_inv() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fp> = (0..20)
+            .map(|_| Fp::new(rng.gen::<u128>().to_biguint().unwrap()))
+            .collect();
+        values[7] = Fp::zero();
+        let inverses = batch_inv(&values);
+        for (v, inv) in values.iter().zip(inverses.iter()) {
+            if v.is_zero() {
+                assert!(inv.is_zero());
+            } else {
+                assert_eq!(*v * *inv, Fp::one());
+            }
+        }
+        assert!(batch_inv(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_select() {
+        let a = Fp::new(5u32.into());
+        let b = -Fp::new(7u32.into());
+        assert_eq!(Fp::select(&a, &b, false), a);
+        assert_eq!(Fp::select(&a, &b, true), b);
+    }
+
+    #[cfg(feature = "ct")]
+    #[test]
+    fn test_conditional_select() {
+        use subtle::{Choice, ConditionallySelectable};
+        let a = Fp::new(5u32.into());
+        let b = -Fp::new(7u32.into());
+        assert_eq!(Fp::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(Fp::conditional_select(&a, &b, Choice::from(1)), b);
+
+        let mut c = a;
+        c.conditional_assign(&b, Choice::from(1));
+        assert_eq!(c, b);
+    }
+
+    #[cfg(feature = "ct")]
+    #[test]
+    fn test_ct_eq() {
+        use subtle::ConstantTimeEq;
+        let a = Fp::new(5u32.into());
+        assert!(bool::from(a.ct_eq(&Fp::new(5u32.into()))));
+        assert!(!bool::from(a.ct_eq(&-a)));
+    }
+
+    #[test]
+    fn test_constants() {
+        let limbs = BigUint::from_slice(
+            &Fp::MODULUS
+                .iter()
+                .flat_map(|&l| [l as u32, (l >> 32) as u32])
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(limbs, *P);
+
+        let u = BigUint::from(crate::BN_PARAM);
+        let poly = |c2: u32| {
+            BigUint::from(36u32) * u.pow(4)
+                + BigUint::from(36u32) * u.pow(3)
+                + BigUint::from(c2) * u.pow(2)
+                + BigUint::from(6u32) * &u
+                + BigUint::one()
+        };
+        assert_eq!(poly(24), *P);
+        assert_eq!(poly(18), *crate::GROUP_ORDER);
+
+        assert!(Fp::ZERO.is_zero());
+        assert_eq!(Fp::ONE.to_biguint(), BigUint::one());
+        assert!(matches!(Fp::from(0u32), Fp::ZERO));
+        assert!(!matches!(Fp::from(1u32), Fp::ZERO));
+    }
+
+    #[test]
+    fn test_from_ints() {
+        assert_eq!(Fp::from(7u32), Fp::new(7u32.into()));
+        assert_eq!(Fp::from(u64::MAX), Fp::new(u64::MAX.into()));
+        assert_eq!(Fp::from(u128::MAX), Fp::new(u128::MAX.into()));
+        assert_eq!(Fp::from(0u64), Fp::zero());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = rand::thread_rng();
+        for x in [Fp::zero(), -Fp::one(), Fp::from(rng.gen::<u128>())] {
+            assert_eq!(Fp::from_bytes_be(&x.to_bytes_be()), Ok(x));
+            assert_eq!(Fp::from_bytes_le(&x.to_bytes_le()), Ok(x));
+            let mut le = x.to_bytes_le();
+            le.reverse();
+            assert_eq!(le, x.to_bytes_be());
+            assert_eq!(BigUint::from_bytes_be(&x.to_bytes_be()), x.to_biguint());
+        }
+
+        let mut p = [0u8; 32];
+        p.copy_from_slice(&P.to_bytes_be());
+        assert_eq!(Fp::from_bytes_be(&p), Err(FpError::TooLarge));
+        assert_eq!(Fp::from_bytes_be(&[0xff; 32]), Err(FpError::TooLarge));
+        p.reverse();
+        assert_eq!(Fp::from_bytes_le(&p), Err(FpError::TooLarge));
+        p[0] -= 1;
+        assert_eq!(Fp::from_bytes_le(&p), Ok(-Fp::one()));
+
+        assert_eq!(Fp::from_bytes_be(&[0u8; 31]), Err(FpError::WrongLength));
+        assert_eq!(Fp::from_bytes_le(&[0u8; 33]), Err(FpError::WrongLength));
+        assert_eq!(Fp::from_bytes_be(&[]), Err(FpError::WrongLength));
+    }
+
+    #[test]
+    fn test_u64_digits() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(Fp::zero().to_u64_le_digits(), [0; 4]);
+        assert_eq!(Fp::one().to_u64_le_digits(), [1, 0, 0, 0]);
+        let mut p_minus_1 = MODULUS;
+        p_minus_1[0] -= 1;
+        assert_eq!((-Fp::one()).to_u64_le_digits(), p_minus_1);
+
+        let mut values = vec![Fp::zero(), Fp::one(), -Fp::one()];
+        values.extend((0..5).map(|_| Fp::from_bytes_wide(&core::array::from_fn(|_| rng.gen()))));
+        for x in values {
+            let digits = x.to_u64_le_digits();
+            assert_eq!(Fp::from_u64_le_digits(digits), Ok(x));
+            let expected: Vec<u64> = x.to_biguint().iter_u64_digits().collect();
+            assert!(digits.iter().zip(&expected).all(|(a, b)| a == b));
+        }
+
+        assert_eq!(Fp::from_u64_le_digits(MODULUS), Err(FpError::TooLarge));
+        assert_eq!(
+            Fp::from_u64_le_digits([u64::MAX; 4]),
+            Err(FpError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fp> = (0..20).map(|_| Fp::from(rng.gen::<u128>())).collect();
+        values.extend([Fp::zero(), -Fp::one(), -Fp::from(2u64), Fp::one()]);
+        values.sort();
+        assert_eq!(values[0], Fp::zero());
+        assert_eq!(values[values.len() - 1], -Fp::one());
+        for w in values.windows(2) {
+            assert!(w[0].to_biguint() <= w[1].to_biguint());
+            assert_eq!(w[0].cmp(&w[1]), w[0].to_bytes_be().cmp(&w[1].to_bytes_be()));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_wide() {
+        let mut rng = rand::thread_rng();
+        for bytes in [[0u8; 64], [0xff; 64], core::array::from_fn(|_| rng.gen())] {
+            let expected = BigUint::from_bytes_be(&bytes) % &*P;
+            assert_eq!(Fp::from_bytes_wide(&bytes).to_biguint(), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let x = Fp::new(BigUint::parse_bytes(b"123456789abcdef0123456789", 16).unwrap());
+        assert_eq!(Fp::try_from(&x.to_bytes_be()[..]), Ok(x));
+        assert_eq!(Fp::try_from(&[1u8, 0][..]), Ok(Fp::from(256u32)));
+        assert_eq!(Fp::try_from(&[][..]), Ok(Fp::zero()));
+
+        let p = P.to_bytes_be();
+        assert_eq!(Fp::try_from(&p[..]), Err(FpError::TooLarge));
+        assert_eq!(Fp::try_from(&[0xff; 33][..]), Err(FpError::TooLarge));
+        let mut below_p = p.clone();
+        *below_p.last_mut().unwrap() -= 1;
+        assert_eq!(Fp::try_from(&below_p[..]), Ok(-Fp::one()));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+        let mut seen: Vec<_> = (0..1000)
+            .map(|_| Fp::random(&mut rng).to_bytes_be())
+            .collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 1000);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+        let mut a = Fp::new(12345u32.into());
+        a.zeroize();
+        assert!(a.is_zero());
+    }
+
+    #[test]
+    fn test_powers() {
+        let base = Fp::new(123456789u32.into());
+        let powers = Fp::powers(&base, 10);
+        assert_eq!(powers.len(), 11);
+        for (i, x) in powers.iter().enumerate() {
+            assert_eq!(*x, base.pow(&BigUint::from(i)));
+        }
+        assert_eq!(Fp::powers(&base, 0), vec![Fp::one()]);
+    }
+
+    #[test]
+    fn test_batch_pow() {
+        let mut rng = rand::thread_rng();
+        let base = Fp::new(rng.gen::<u128>().to_biguint().unwrap());
+        let mut exponents: Vec<BigUint> = (0..10)
+            .map(|_| rng.gen::<u128>().to_biguint().unwrap())
+            .collect();
+        exponents.push(BigUint::from(0u32));
+        exponents.push(&*P - 1u32);
+
+        let results = Fp::batch_pow(&base, &exponents);
+        for (e, x) in exponents.iter().zip(results.iter()) {
+            assert_eq!(*x, base.pow(e));
+        }
+        assert!(Fp::batch_pow(&base, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_montgomery_matches_biguint() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let a: BigUint = BigUint::from_bytes_le(&rng.gen::<[u8; 32]>()) % &*P;
+            let b: BigUint = BigUint::from_bytes_le(&rng.gen::<[u8; 32]>()) % &*P;
+            let (x, y) = (Fp::new(a.clone()), Fp::new(b.clone()));
+
+            assert_eq!(x.to_biguint(), a);
+            assert_eq!((x + y).to_biguint(), (&a + &b) % &*P);
+            assert_eq!((x - y).to_biguint(), (&a + &*P - &b) % &*P);
+            assert_eq!((x * y).to_biguint(), (&a * &b) % &*P);
+            assert_eq!((-x).to_biguint(), (&*P - &a) % &*P);
+        }
+
+        // Values at the top of the range
+        let max = Fp::new(&*P - BigUint::one());
+        assert_eq!(max + Fp::one(), Fp::zero());
+        assert_eq!(max * max, Fp::one());
+    }
+
+    #[test]
+    fn test_display() {
+        let a = Fp::new(0xabcdu32.to_biguint().unwrap());
+        assert_eq!(a.to_string(), "43981");
+        assert_eq!(
+            format!("{:x}", a),
+            "000000000000000000000000000000000000000000000000000000000000abcd"
+        );
+        assert_eq!(
+            format!("{:#x}", -Fp::one()),
+            "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0".parse::<Fp>(), Ok(Fp::zero()));
+        assert_eq!("1".parse::<Fp>(), Ok(Fp::one()));
+        assert_eq!("0x1".parse::<Fp>(), Ok(Fp::one()));
+        assert_eq!("0xAbCd".parse::<Fp>(), "43981".parse::<Fp>());
+
+        let p_minus_one = (&*P - BigUint::one()).to_string();
+        assert_eq!(p_minus_one.parse::<Fp>(), Ok(-Fp::one()));
+        assert_eq!(p_minus_one.parse::<Fp>().unwrap().to_string(), p_minus_one);
+        let hex = format!("{:#x}", -Fp::one());
+        assert_eq!(hex.parse::<Fp>(), Ok(-Fp::one()));
+
+        assert_eq!(P.to_string().parse::<Fp>(), Err(ParseFpError::TooLarge));
+        assert_eq!(
+            (&*P + BigUint::one()).to_string().parse::<Fp>(),
+            Err(ParseFpError::TooLarge)
+        );
+        assert_eq!("".parse::<Fp>(), Err(ParseFpError::Empty));
+        assert_eq!("0x".parse::<Fp>(), Err(ParseFpError::Empty));
+        assert_eq!("12a".parse::<Fp>(), Err(ParseFpError::InvalidDigit));
+        assert_eq!("-1".parse::<Fp>(), Err(ParseFpError::InvalidDigit));
+        assert_eq!("1_000".parse::<Fp>(), Err(ParseFpError::InvalidDigit));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = Fp::new(0x1234u32.to_biguint().unwrap());
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            json,
+            "\"0x0000000000000000000000000000000000000000000000000000000000001234\""
+        );
+        assert_eq!(serde_json::from_str::<Fp>(&json).unwrap(), a);
+        assert_eq!(serde_json::from_str::<Fp>("\"0x1234\"").unwrap(), a);
+
+        let max = -Fp::one();
+        assert_eq!(
+            serde_json::from_str::<Fp>(&serde_json::to_string(&max).unwrap()).unwrap(),
+            max
+        );
+
+        let p = format!("\"0x{}\"", P.to_str_radix(16));
+        assert!(serde_json::from_str::<Fp>(&p).is_err());
+        assert!(serde_json::from_str::<Fp>("\"1234\"").is_err());
+        assert!(serde_json::from_str::<Fp>("\"0xzz\"").is_err());
     }
 }
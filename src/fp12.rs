@@ -1,20 +1,27 @@
+use crate::constants::FROBENIUS_COEFF_FP12_C1;
+use crate::error::Error;
 use crate::fp2::Fp2;
 use crate::fp6::Fp6;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "alloc")]
 use num_bigint::BigUint;
-use num_traits::Zero;
-use std::ops::{Add, Mul, Neg, Sub};
+
+/// Non-adjacent form of `BN_PARAM`, least significant digit first
+const U_NAF: [i8; 64] = crate::naf(crate::BN_PARAM as u128);
 
 /// Fp12 represents the degree-12 extension Fp12 = Fp6[w] / (w² - v)
 /// An element is represented as c0 + c1*w
 /// where w² = v (a non-residue in Fp6)
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fp12 {
     pub c0: Fp6,
     pub c1: Fp6,
 }
 
 impl Fp12 {
-    pub fn new(c0: Fp6, c1: Fp6) -> Self {
+    pub const fn new(c0: Fp6, c1: Fp6) -> Self {
         Fp12 { c0, c1 }
     }
 
@@ -32,6 +39,12 @@ impl Fp12 {
         }
     }
 
+    /// A uniformly random element, see `Fp::random`
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Fp12::new(Fp6::random(rng), Fp6::random(rng))
+    }
+
     /// Non-residue for Fp12: v in Fp6 = (0, 1, 0)
     #[allow(dead_code)]
     fn non_residue() -> Fp6 {
@@ -41,17 +54,37 @@ impl Fp12 {
     /// Multiply by non-residue (multiply by v)
     fn mul_by_non_residue(a: &Fp6) -> Fp6 {
         // (c0, c1, c2) * v = (c2*ξ, c0, c1) where ξ = u+9
-        Fp6::new(
-            Fp6::mul_by_non_residue(&a.c2),
-            a.c0.clone(),
-            a.c1.clone(),
-        )
+        Fp6::new(Fp6::mul_by_non_residue(&a.c2), a.c0.clone(), a.c1.clone())
+    }
+
+    /// Complex squaring: (a + bw)² = (a + b)(a + bv) - ab - abv + 2ab*w,
+    /// 2 Fp6 multiplications instead of 3
+    pub fn square(&self) -> Self {
+        let ab = &self.c0 * &self.c1;
+        let t = &(&self.c0 + &self.c1) * &(&self.c0 + &Self::mul_by_non_residue(&self.c1));
+        Fp12 {
+            c0: &(&t - &ab) - &Self::mul_by_non_residue(&ab),
+            c1: &ab + &ab,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
     }
 
+    /// The inverse, or `Error::ZeroInverse` for zero
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.inv())
+    }
+
+    /// Panics if `self` is zero; `try_inv` returns an error instead.
     pub fn inv(&self) -> Self {
         // (c0 + c1*w)^(-1) = (c0 - c1*w) / (c0² - c1²*v)
-        let c0_sq = &self.c0 * &self.c0;
-        let c1_sq = &self.c1 * &self.c1;
+        let c0_sq = self.c0.square();
+        let c1_sq = self.c1.square();
         let t = &c0_sq - &Self::mul_by_non_residue(&c1_sq);
         let t_inv = t.inv();
 
@@ -61,31 +94,259 @@ impl Fp12 {
         }
     }
 
-    /// Frobenius endomorphism
-    pub fn frobenius_map(&self, _power: usize) -> Self {
-        // TODO: Implement proper Frobenius map with frobenius coefficients
-        // For now, this is a placeholder
-        self.clone()
+    /// Checks f^(p^6 + 1) == 1, i.e. membership in the cyclotomic subgroup
+    /// that contains GT. f^(p^6) is the conjugate, so this costs a single
+    /// multiplication.
+    pub fn is_cyclotomic(&self) -> bool {
+        &self.conjugate() * self == Fp12::one()
     }
 
-    /// Exponentiation
-    pub fn pow(&self, exp: &BigUint) -> Self {
+    /// Norm to Fp6, f · f^(p^6) = c0² - c1²·v. It is 1 exactly when
+    /// `is_cyclotomic` holds, so in particular for every element of GT.
+    pub fn norm_fp6(&self) -> Fp6 {
+        &self.c0.square() - &Self::mul_by_non_residue(&self.c1.square())
+    }
+
+    /// Norm to Fp2, the Fp6-norm followed by the Fp6 → Fp2 norm
+    /// a · a^(p^2) · a^(p^4). 1 for every element of GT.
+    pub fn norm_fp2(&self) -> Fp2 {
+        let a = self.norm_fp6();
+        let n = &(&a * &a.frobenius_map(2)) * &a.frobenius_map(4);
+        debug_assert!(n.c1.is_zero() && n.c2.is_zero());
+        n.c0
+    }
+
+    /// Sanity check for pairing outputs, active only in debug builds:
+    /// asserts that both norms are 1 and that f^(p^4 - p^2 + 1) = 1, the
+    /// condition for the order-Φ12(p) subgroup that contains GT. A failure
+    /// means the final exponentiation (or its input) is broken.
+    pub(crate) fn debug_check_gt_element(&self) {
+        debug_assert!(
+            self.norm_fp6() == Fp6::one(),
+            "GT element has Fp6-norm != 1"
+        );
+        debug_assert!(
+            self.norm_fp2() == Fp2::one(),
+            "GT element has Fp2-norm != 1"
+        );
+        debug_assert!(
+            &self.frobenius_map(4) * self == self.frobenius_map(2),
+            "GT element is not in the cyclotomic subgroup"
+        );
+    }
+
+    /// Conjugate c0 - c1*w, which is f^(p^6). For f in the cyclotomic
+    /// subgroup (including all of GT) this is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Fp12 {
+            c0: self.c0.clone(),
+            c1: -self.c1.clone(),
+        }
+    }
+
+    /// Frobenius endomorphism: f^(p^power)
+    /// σ(c0 + c1*w) = σ(c0) + σ(c1)*w^p where w^(p^k) = ξ^((p^k - 1) / 6) * w
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        let coeff = &FROBENIUS_COEFF_FP12_C1[power % 12];
+        let c1 = self.c1.frobenius_map(power);
+        Fp12 {
+            c0: self.c0.frobenius_map(power),
+            c1: c1.mul_by_fp2(coeff),
+        }
+    }
+
+    /// Multiply by a line evaluation ell_0 + ell_w*w + ell_vw*v*w
+    ///
+    /// With the D-type sextic twist used for G2, lines evaluated at a G1
+    /// point only have nonzero coefficients at 1, w and v*w (the "034"
+    /// sparse form). Needs 13 Fp2 multiplications instead of 18.
+    pub fn mul_by_line_eval(&self, ell_0: &Fp2, ell_w: &Fp2, ell_vw: &Fp2) -> Self {
+        // (a0 + a1*w) * (b0 + b1*w) with b0 = ell_0 and b1 = ell_w + ell_vw*v
+        let a = self.c0.mul_by_fp2(ell_0);
+        let b = self.c1.mul_by_01(ell_w, ell_vw);
+        let e = (&self.c0 + &self.c1).mul_by_01(&(ell_0 + ell_w), ell_vw);
+
+        Fp12 {
+            c0: &a + &Self::mul_by_non_residue(&b),
+            c1: &(&e - &a) - &b,
+        }
+    }
+
+    /// Squaring for elements of the cyclotomic subgroup, i.e. elements
+    /// with f^(p⁴ - p² + 1) = 1 such as the output of the easy part of the
+    /// final exponentiation.
+    ///
+    /// Uses the Granger–Scott formula ("Faster Squaring in the Cyclotomic
+    /// Subgroup of Sixth Degree Extensions"), which views Fp12 as a cubic
+    /// extension of Fp4 = Fp2[t] / (t² - ξ) and needs 9 Fp2 multiplications
+    /// instead of 18. The result is wrong for elements outside the subgroup.
+    pub fn cyclotomic_square(&self) -> Self {
+        let nr = Fp6::mul_by_non_residue;
+
+        let z0 = &self.c0.c0;
+        let z4 = &self.c0.c1;
+        let z3 = &self.c0.c2;
+        let z2 = &self.c1.c0;
+        let z1 = &self.c1.c1;
+        let z5 = &self.c1.c2;
+
+        // Squares in Fp4: (a + b*t)² = (a² + ξb²) + 2ab*t
+        let fp4_square = |a: &Fp2, b: &Fp2| -> (Fp2, Fp2) {
+            let ab = a * b;
+            let c0 = &(&(a + b) * &(&nr(b) + a)) - &ab - nr(&ab);
+            let c1 = &ab + &ab;
+            (c0, c1)
+        };
+        let (t0, t1) = fp4_square(z0, z1);
+        let (t2, t3) = fp4_square(z2, z3);
+        let (t4, t5) = fp4_square(z4, z5);
+
+        // 3*t - 2*z
+        let sub3 = |t: &Fp2, z: &Fp2| -> Fp2 {
+            let d = t - z;
+            &(&d + &d) + t
+        };
+        // 3*t + 2*z
+        let add3 = |t: &Fp2, z: &Fp2| -> Fp2 {
+            let s = t + z;
+            &(&s + &s) + t
+        };
+
+        let z0 = sub3(&t0, z0);
+        let z1 = add3(&t1, z1);
+        let z2 = add3(&nr(&t5), z2);
+        let z3 = sub3(&t4, z3);
+        let z4 = sub3(&t2, z4);
+        let z5 = add3(&t3, z5);
+
+        Fp12 {
+            c0: Fp6::new(z0, z4, z3),
+            c1: Fp6::new(z2, z1, z5),
+        }
+    }
+
+    /// Exponentiation for elements of the cyclotomic subgroup, using
+    /// `cyclotomic_square` in place of full squarings
+    #[cfg(feature = "alloc")]
+    pub fn cyclotomic_exp(&self, exp: &BigUint) -> Self {
+        self.cyclotomic_exp_vartime(&exp.to_u64_digits())
+    }
+
+    /// `cyclotomic_exp` for an exponent given as little-endian 64-bit
+    /// limbs, as `Fp::pow_vartime`. The running time depends on the
+    /// exponent, so it must not be secret.
+    pub fn cyclotomic_exp_vartime(&self, exp: &[u64]) -> Self {
         let mut res = Self::one();
-        let mut base = self.clone();
-        let mut e = exp.clone();
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.cyclotomic_square();
+                if (limb >> i) & 1 == 1 {
+                    res = &res * self;
+                }
+            }
+        }
+        res
+    }
 
-        while !e.is_zero() {
-            if e.bit(0) {
-                res = &res * &base;
+    /// f^u for f in the cyclotomic subgroup, where u is `BN_PARAM`.
+    ///
+    /// Inversion in the cyclotomic subgroup is conjugation, which costs
+    /// nothing, so this walks the non-adjacent form of u. That form has 24
+    /// non-zero digits where the binary form has 28 set bits, so it takes 62
+    /// cyclotomic squarings and 23 multiplications instead of 27.
+    pub fn exp_by_u(&self) -> Self {
+        let inv = self.conjugate();
+        let top = U_NAF.iter().rposition(|&d| d != 0).unwrap();
+        let mut res = self.clone();
+        for &digit in U_NAF[..top].iter().rev() {
+            res = res.cyclotomic_square();
+            match digit {
+                1 => res = &res * self,
+                -1 => res = &res * &inv,
+                _ => {}
+            }
+        }
+        res
+    }
+
+    /// f^(-u) for f in the cyclotomic subgroup
+    fn exp_by_neg_u(&self) -> Self {
+        self.exp_by_u().conjugate()
+    }
+
+    /// Hard part of the final exponentiation: f^((p^4 - p^2 + 1) / r)
+    ///
+    /// Follows Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster
+    /// hashing to G2", which computes the multiple
+    ///
+    ///   f^(2u(6u² + 3u + 1) * (p^4 - p^2 + 1) / r)
+    ///     = f^(λ3 * p^3 + λ2 * p^2 + λ1 * p + λ0)
+    ///
+    /// with
+    ///   λ3 = 12u³ + 6u² + 4u - 1
+    ///   λ2 = 12u³ + 6u² + 6u
+    ///   λ1 = 12u³ + 6u² + 4u
+    ///   λ0 = 12u³ + 12u² + 6u + 1
+    ///
+    /// using three exponentiations by u and a handful of Frobenius maps.
+    /// The input must be in the cyclotomic subgroup.
+    pub fn exp_hard_part(&self) -> Self {
+        let f = self;
+        let y0 = f.exp_by_neg_u(); // f^(-u)
+        let y1 = y0.cyclotomic_square(); // f^(-2u)
+        let y2 = y1.cyclotomic_square(); // f^(-4u)
+        let y3 = &y2 * &y1; // f^(-6u)
+        let y4 = y3.exp_by_neg_u(); // f^(6u²)
+        let y5 = y4.cyclotomic_square(); // f^(12u²)
+        let y6 = y5.exp_by_neg_u(); // f^(-12u³)
+        let y3 = y3.conjugate(); // f^(6u)
+        let y6 = y6.conjugate(); // f^(12u³)
+        let y7 = &y6 * &y4; // f^(12u³ + 6u²)
+        let y8 = &y7 * &y3; // f^(12u³ + 6u² + 6u)
+        let y9 = &y8 * &y1; // f^(12u³ + 6u² + 4u)
+        let y10 = &y8 * &y4; // f^(12u³ + 12u² + 6u)
+        let y11 = &y10 * f; // f^λ0
+        let y12 = y9.frobenius_map(1); // f^(λ1 * p)
+        let y13 = &y12 * &y11;
+        let y8 = y8.frobenius_map(2); // f^(λ2 * p^2)
+        let y14 = &y8 * &y13;
+        let y15 = (&f.conjugate() * &y9).frobenius_map(3); // f^(λ3 * p^3)
+        &y15 * &y14
+    }
+
+    /// Exponentiation by left-to-right square-and-multiply. This works for
+    /// any element; values in the cyclotomic subgroup (such as pairing
+    /// outputs) should use the cheaper `cyclotomic_exp`.
+    #[cfg(feature = "alloc")]
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        let mut res = Self::one();
+        for i in (0..exp.bits()).rev() {
+            res = res.square();
+            if exp.bit(i) {
+                res = &res * self;
             }
-            base = &base * &base;
-            e >>= 1;
         }
 
         res
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fp12 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+/// The default is one, the multiplicative identity, since Fp12 values
+/// are mostly used as accumulators of pairing products
+impl Default for Fp12 {
+    fn default() -> Self {
+        Fp12::one()
+    }
+}
+
 impl Add for Fp12 {
     type Output = Fp12;
     fn add(self, rhs: Fp12) -> Fp12 {
@@ -96,7 +357,7 @@ impl Add for Fp12 {
     }
 }
 
-impl<'a, 'b> Add<&'b Fp12> for &'a Fp12 {
+impl<'b> Add<&'b Fp12> for &Fp12 {
     type Output = Fp12;
     fn add(self, rhs: &'b Fp12) -> Fp12 {
         Fp12 {
@@ -116,7 +377,7 @@ impl Sub for Fp12 {
     }
 }
 
-impl<'a, 'b> Sub<&'b Fp12> for &'a Fp12 {
+impl<'b> Sub<&'b Fp12> for &Fp12 {
     type Output = Fp12;
     fn sub(self, rhs: &'b Fp12) -> Fp12 {
         Fp12 {
@@ -142,7 +403,7 @@ impl Mul for Fp12 {
     }
 }
 
-impl<'a, 'b> Mul<&'b Fp12> for &'a Fp12 {
+impl<'b> Mul<&'b Fp12> for &Fp12 {
     type Output = Fp12;
     fn mul(self, rhs: &'b Fp12) -> Fp12 {
         // (a0 + a1*w)(b0 + b1*w) = (a0*b0 + a1*b1*v) + (a0*b1 + a1*b0)*w
@@ -168,10 +429,49 @@ impl Neg for Fp12 {
     }
 }
 
+impl Fp12 {
+    fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        fmt_fp2: fn(&Fp2, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        let terms = [
+            (&self.c0.c0, ""),
+            (&self.c0.c1, "*v"),
+            (&self.c0.c2, "*v^2"),
+            (&self.c1.c0, "*w"),
+            (&self.c1.c1, "*v*w"),
+            (&self.c1.c2, "*v^2*w"),
+        ];
+        for (i, (c, basis)) in terms.iter().enumerate() {
+            f.write_str(if i == 0 { "(" } else { "\n+ (" })?;
+            fmt_fp2(c, f)?;
+            write!(f, "){}", basis)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displayed over six lines, one per Fp2 coefficient of the basis
+/// 1, v, v², w, vw, v²w
+impl fmt::Display for Fp12 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, <Fp2 as fmt::Display>::fmt)
+    }
+}
+
+impl fmt::LowerHex for Fp12 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, <Fp2 as fmt::LowerHex>::fmt)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::fp::Fp;
+    use num_traits::Zero;
+    use rand::Rng;
 
     #[test]
     fn test_basic_ops() {
@@ -194,6 +494,182 @@ mod tests {
         assert_eq!(prod, a);
     }
 
+    fn random_fp2(rng: &mut impl Rng) -> Fp2 {
+        Fp2::new(
+            Fp::new(rng.gen::<u128>().into()),
+            Fp::new(rng.gen::<u128>().into()),
+        )
+    }
+
+    fn random_fp12(rng: &mut impl Rng) -> Fp12 {
+        let mut fp6 = || Fp6::new(random_fp2(rng), random_fp2(rng), random_fp2(rng));
+        Fp12::new(fp6(), fp6())
+    }
+
+    /// Maps an element into the cyclotomic subgroup via the easy part of
+    /// the final exponentiation
+    fn cyclotomic_element() -> Fp12 {
+        let f = Fp12::new(
+            Fp6::new(
+                Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
+                Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
+                Fp2::new(Fp::new(5u32.into()), Fp::new(6u32.into())),
+            ),
+            Fp6::new(
+                Fp2::new(Fp::new(7u32.into()), Fp::new(8u32.into())),
+                Fp2::new(Fp::new(9u32.into()), Fp::new(10u32.into())),
+                Fp2::new(Fp::new(11u32.into()), Fp::new(12u32.into())),
+            ),
+        );
+        let f1 = &f.frobenius_map(6) * &f.inv();
+        &f1.frobenius_map(2) * &f1
+    }
+
+    #[test]
+    fn test_frobenius_map_matches_pow() {
+        let f = cyclotomic_element();
+        let p = crate::fp::P.clone();
+        assert_eq!(f.frobenius_map(1), f.pow(&p));
+        assert_eq!(f.frobenius_map(2), f.pow(&(&p * &p)));
+        assert_eq!(f.frobenius_map(3), f.pow(&(&p * &p * &p)));
+    }
+
+    #[test]
+    fn test_norms() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp12(&mut rng);
+        let b = random_fp12(&mut rng);
+
+        let n6 = &a * &a.conjugate();
+        assert_eq!(n6, Fp12::new(a.norm_fp6(), Fp6::zero()));
+        let n2 = (0..6).fold(Fp12::one(), |acc, k| &acc * &a.frobenius_map(2 * k));
+        assert_eq!(
+            n2,
+            Fp12::new(
+                Fp6::new(a.norm_fp2(), Fp2::zero(), Fp2::zero()),
+                Fp6::zero()
+            )
+        );
+        assert_eq!((&a * &b).norm_fp2(), &a.norm_fp2() * &b.norm_fp2());
+
+        let f = cyclotomic_element();
+        assert_eq!(f.norm_fp6(), Fp6::one());
+        assert_eq!(f.norm_fp2(), Fp2::one());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "norm")]
+    fn test_debug_check_gt_element() {
+        let f = cyclotomic_element();
+        f.exp_hard_part().debug_check_gt_element();
+        (&f + &Fp12::one()).debug_check_gt_element();
+    }
+
+    #[test]
+    fn test_exp_by_u() {
+        let value = U_NAF
+            .iter()
+            .rev()
+            .fold(0i128, |acc, &d| 2 * acc + d as i128);
+        assert_eq!(value, crate::BN_PARAM as i128);
+        assert!(U_NAF.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+
+        let f = cyclotomic_element();
+        assert_eq!(f.exp_by_u(), f.pow(&BigUint::from(crate::BN_PARAM)));
+        assert_eq!(&f.exp_by_neg_u() * &f.exp_by_u(), Fp12::one());
+    }
+
+    #[test]
+    fn test_frobenius_map_composition() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp12(&mut rng);
+        let b = random_fp12(&mut rng);
+
+        // σ^12 is the identity on Fp12, and σ^k agrees with k applications
+        let mut x = a.clone();
+        for k in 1..=12 {
+            x = x.frobenius_map(1);
+            assert_eq!(a.frobenius_map(k), x);
+        }
+        assert_eq!(x, a);
+
+        // It is a ring homomorphism
+        assert_eq!(
+            (&a * &b).frobenius_map(1),
+            &a.frobenius_map(1) * &b.frobenius_map(1)
+        );
+    }
+
+    #[test]
+    fn test_mul_by_line_eval() {
+        let mut rng = rand::thread_rng();
+        let f = random_fp12(&mut rng);
+        let (ell_0, ell_w, ell_vw) = (
+            random_fp2(&mut rng),
+            random_fp2(&mut rng),
+            random_fp2(&mut rng),
+        );
+
+        let line = Fp12::new(
+            Fp6::new(ell_0.clone(), Fp2::zero(), Fp2::zero()),
+            Fp6::new(ell_w.clone(), ell_vw.clone(), Fp2::zero()),
+        );
+        assert_eq!(f.mul_by_line_eval(&ell_0, &ell_w, &ell_vw), &f * &line);
+    }
+
+    #[test]
+    fn test_square() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let f = random_fp12(&mut rng);
+            assert_eq!(f.square(), &f * &f);
+        }
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let f = cyclotomic_element();
+        assert_eq!(f.conjugate(), f.frobenius_map(6));
+        assert_eq!(&f * &f.conjugate(), Fp12::one());
+
+        let g = crate::pairing::final_exponentiation(&f);
+        assert_eq!(&g * &g.conjugate(), Fp12::one());
+    }
+
+    #[test]
+    fn test_is_cyclotomic() {
+        assert!(Fp12::one().is_cyclotomic());
+        assert!(cyclotomic_element().is_cyclotomic());
+        let f = Fp12::new(Fp6::one(), Fp6::one());
+        assert!(!f.is_cyclotomic());
+        assert_eq!(f.frobenius_map(6), f.conjugate());
+    }
+
+    #[test]
+    fn test_cyclotomic_square() {
+        let f = cyclotomic_element();
+        assert_eq!(f.cyclotomic_square(), &f * &f);
+    }
+
+    #[test]
+    fn test_cyclotomic_exp() {
+        let f = cyclotomic_element();
+        let exp = BigUint::from(4965661367192848881u64);
+        assert_eq!(f.cyclotomic_exp(&exp), f.pow(&exp));
+        assert_eq!(f.cyclotomic_exp(&BigUint::zero()), Fp12::one());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut a = Fp12::one();
+        a.c1.c2 = Fp2::new(Fp::new(5u32.into()), Fp::one());
+        assert_eq!(
+            a.to_string(),
+            "(1 + 0*u)\n+ (0 + 0*u)*v\n+ (0 + 0*u)*v^2\n+ (0 + 0*u)*w\n+ (0 + 0*u)*v*w\n+ (5 + 1*u)*v^2*w"
+        );
+    }
+
     #[test]
     fn test_inverse() {
         let a = Fp12::new(
@@ -212,4 +688,12 @@ mod tests {
         let prod = &a * &inv;
         assert_eq!(prod, Fp12::one());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = cyclotomic_element();
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(serde_json::from_str::<Fp12>(&json).unwrap(), a);
+    }
 }
@@ -1,17 +1,29 @@
-use crate::fp::Fp;
-use std::ops::{Add, Mul, Neg, Sub};
+use crate::error::Error;
+#[cfg(feature = "alloc")]
+use crate::fp::P;
+use crate::fp::{Fp, ParseFpError};
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use core::str::FromStr;
+#[cfg(feature = "alloc")]
+use num_bigint::BigUint;
 
 /// Fp2 represents the quadratic extension field Fp2 = Fp[u] / (u² + 1)
 /// where u² = -1
 /// An element is represented as c0 + c1*u
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fp2 {
     pub c0: Fp,
     pub c1: Fp,
 }
 
 impl Fp2 {
-    pub fn new(c0: Fp, c1: Fp) -> Self {
+    pub const fn new(c0: Fp, c1: Fp) -> Self {
         Fp2 { c0, c1 }
     }
 
@@ -29,26 +41,176 @@ impl Fp2 {
         }
     }
 
+    /// A uniformly random element, see `Fp::random`
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Fp2::new(Fp::random(rng), Fp::random(rng))
+    }
+
+    /// (a + bu)² = (a + b)(a - b) + 2ab*u, two Fp multiplications
+    /// instead of three
+    pub fn square(&self) -> Self {
+        let ab = self.c0 * self.c1;
+        Fp2 {
+            c0: (self.c0 + self.c1) * (self.c0 - self.c1),
+            c1: ab + ab,
+        }
+    }
+
+    /// self^exp, see `pow_vartime`
+    #[cfg(feature = "alloc")]
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        self.pow_vartime(&exp.to_u64_digits())
+    }
+
+    /// self^exp for an exponent given as little-endian 64-bit limbs, by
+    /// square-and-multiply, as `Fp::pow_vartime`. The running time depends
+    /// on the exponent, so it must not be secret.
+    pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+        let mut res = Fp2::one();
+        let mut started = false;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                if started {
+                    res = res.square();
+                }
+                if (limb >> i) & 1 == 1 {
+                    res = &res * self;
+                    started = true;
+                }
+            }
+        }
+        res
+    }
+
+    /// The field order minus one, p² - 1, which is the order of the
+    /// multiplicative group Fp2*
+    #[cfg(feature = "alloc")]
+    pub fn order_minus_one() -> BigUint {
+        &*P * &*P - 1u32
+    }
+
     /// Conjugate: (a + bu)* = a - bu
     pub fn conjugate(&self) -> Self {
         Fp2 {
-            c0: self.c0.clone(),
-            c1: -self.c1.clone(),
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    /// The norm N(a) = a·ā = a0² + a1², an element of Fp
+    pub fn norm(&self) -> Fp {
+        self.c0 * self.c0 + self.c1 * self.c1
+    }
+
+    /// Whether N(a) = 1, i.e. a lies in the order p + 1 subgroup of Fp2*
+    /// that contains the roots of unity of order dividing p + 1
+    pub fn is_norm_one(&self) -> bool {
+        self.norm() == Fp::one()
+    }
+
+    /// The trace Tr(a) = a + ā = 2·a0, an element of Fp
+    pub fn trace(&self) -> Fp {
+        self.c0 + self.c0
+    }
+
+    /// Multiply by a base field element: (a + bu) * s = as + bs*u
+    pub fn mul_by_fp(&self, scalar: &Fp) -> Self {
+        Fp2 {
+            c0: self.c0 * *scalar,
+            c1: self.c1 * *scalar,
+        }
+    }
+
+    /// Frobenius endomorphism: (a + bu)^(p^power)
+    /// Since p ≡ 3 (mod 4), u^p = -u, so odd powers conjugate and even
+    /// powers are the identity
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        if power % 2 == 1 {
+            self.conjugate()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Whether self is a square: a is a square in Fp2 iff its norm
+    /// a0² + a1² is a square in Fp
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.norm().is_quadratic_residue()
+    }
+
+    /// sgn0 from RFC 9380 section 4.1: the parity of c0, or of c1 when c0 is
+    /// zero
+    pub fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+
+    /// Square root, or None if self is not a square.
+    ///
+    /// For a = a0 + a1*u with norm N = a0² + a1², a is a square iff N is a
+    /// square in Fp. A root x0 + x1*u then satisfies x0² = (a0 ± √N) / 2
+    /// and x1 = a1 / (2*x0).
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.c1 == Fp::zero() {
+            // a0 or -a0 is a square in Fp since -1 is not
+            return match self.c0.sqrt() {
+                Some(x0) => Some(Fp2::new(x0, Fp::zero())),
+                None => (-self.c0).sqrt().map(|x1| Fp2::new(Fp::zero(), x1)),
+            };
+        }
+
+        let n = self.norm().sqrt()?;
+        let two_inv = Fp::from(2u32).inv();
+        // Exactly one of (a0 ± √N) / 2 is a square when a1 ≠ 0
+        let mut delta = (self.c0 + n) * two_inv;
+        if !delta.is_quadratic_residue() {
+            delta = (self.c0 - n) * two_inv;
         }
+        let x0 = delta.sqrt()?;
+        let x1 = self.c1 * (x0 + x0).inv();
+        Some(Fp2::new(x0, x1))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// The inverse, or `Error::ZeroInverse` for zero
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.inv())
     }
 
     /// Inverse: (a + bu)^(-1) = (a - bu) / (a² + b²)
     /// since u² = -1, norm = a² - b²u² = a² + b²
+    ///
+    /// Panics if `self` is zero; `try_inv` returns an error instead.
     pub fn inv(&self) -> Self {
-        let norm = self.c0.clone() * self.c0.clone() + self.c1.clone() * self.c1.clone();
-        let norm_inv = norm.inv();
+        let norm_inv = self.norm().inv();
         Fp2 {
-            c0: self.c0.clone() * norm_inv.clone(),
-            c1: -self.c1.clone() * norm_inv,
+            c0: self.c0 * norm_inv,
+            c1: -self.c1 * norm_inv,
         }
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fp2 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+/// The default is zero
+impl Default for Fp2 {
+    fn default() -> Self {
+        Fp2::zero()
+    }
+}
+
 /// Addition: (a + bu) + (c + du) = (a + c) + (b + d)u
 impl Add for Fp2 {
     type Output = Fp2;
@@ -60,12 +222,12 @@ impl Add for Fp2 {
     }
 }
 
-impl<'a, 'b> Add<&'b Fp2> for &'a Fp2 {
+impl<'b> Add<&'b Fp2> for &Fp2 {
     type Output = Fp2;
     fn add(self, rhs: &'b Fp2) -> Fp2 {
         Fp2 {
-            c0: self.c0.clone() + rhs.c0.clone(),
-            c1: self.c1.clone() + rhs.c1.clone(),
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
         }
     }
 }
@@ -81,12 +243,12 @@ impl Sub for Fp2 {
     }
 }
 
-impl<'a, 'b> Sub<&'b Fp2> for &'a Fp2 {
+impl<'b> Sub<&'b Fp2> for &Fp2 {
     type Output = Fp2;
     fn sub(self, rhs: &'b Fp2) -> Fp2 {
         Fp2 {
-            c0: self.c0.clone() - rhs.c0.clone(),
-            c1: self.c1.clone() - rhs.c1.clone(),
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
         }
     }
 }
@@ -96,11 +258,9 @@ impl<'a, 'b> Sub<&'b Fp2> for &'a Fp2 {
 impl Mul for Fp2 {
     type Output = Fp2;
     fn mul(self, rhs: Fp2) -> Fp2 {
-        let ac = self.c0.clone() * rhs.c0.clone();
-        let bd = self.c1.clone() * rhs.c1.clone();
-        let ad_plus_bc = (self.c0.clone() + self.c1.clone()) * (rhs.c0.clone() + rhs.c1.clone())
-            - ac.clone()
-            - bd.clone();
+        let ac = self.c0 * rhs.c0;
+        let bd = self.c1 * rhs.c1;
+        let ad_plus_bc = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - ac - bd;
         Fp2 {
             c0: ac - bd,
             c1: ad_plus_bc,
@@ -108,14 +268,12 @@ impl Mul for Fp2 {
     }
 }
 
-impl<'a, 'b> Mul<&'b Fp2> for &'a Fp2 {
+impl<'b> Mul<&'b Fp2> for &Fp2 {
     type Output = Fp2;
     fn mul(self, rhs: &'b Fp2) -> Fp2 {
-        let ac = self.c0.clone() * rhs.c0.clone();
-        let bd = self.c1.clone() * rhs.c1.clone();
-        let ad_plus_bc = (self.c0.clone() + self.c1.clone()) * (rhs.c0.clone() + rhs.c1.clone())
-            - ac.clone()
-            - bd.clone();
+        let ac = self.c0 * rhs.c0;
+        let bd = self.c1 * rhs.c1;
+        let ad_plus_bc = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - ac - bd;
         Fp2 {
             c0: ac - bd,
             c1: ad_plus_bc,
@@ -134,6 +292,63 @@ impl Neg for Fp2 {
     }
 }
 
+/// Displayed as "c0 + c1*u"
+impl fmt::Display for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.c0, f)?;
+        f.write_str(" + ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        f.write_str("*u")
+    }
+}
+
+impl fmt::LowerHex for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.c0, f)?;
+        f.write_str(" + ")?;
+        fmt::LowerHex::fmt(&self.c1, f)?;
+        f.write_str("*u")
+    }
+}
+
+/// Parses "c0,c1", each component as accepted by `Fp::from_str`.
+/// Whitespace around the components is ignored.
+impl FromStr for Fp2 {
+    type Err = ParseFpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(c0), Some(c1), None) => Ok(Fp2::new(c0.trim().parse()?, c1.trim().parse()?)),
+            _ => Err(ParseFpError::InvalidComponentCount),
+        }
+    }
+}
+
+/// Inverts every element with a single Fp2 inversion (Montgomery's trick).
+/// Zero has no inverse and is mapped to zero.
+#[cfg(feature = "alloc")]
+pub fn batch_inv(values: &[Fp2]) -> Vec<Fp2> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fp2::one();
+    for v in values {
+        prefix.push(acc.clone());
+        if !v.is_zero() {
+            acc = &acc * v;
+        }
+    }
+
+    let mut inv = acc.inv();
+    let mut res = vec![Fp2::zero(); values.len()];
+    for (i, v) in values.iter().enumerate().rev() {
+        if !v.is_zero() {
+            res[i] = &inv * &prefix[i];
+            inv = &inv * v;
+        }
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,6 +413,159 @@ mod tests {
         assert_eq!(conj.c1, -Fp::new(5u32.into()));
     }
 
+    #[test]
+    fn test_norm_and_trace() {
+        let mut rng = rand::thread_rng();
+        let mut random = || Fp2::new(Fp::from(rng.gen::<u64>()), Fp::from(rng.gen::<u64>()));
+        let (a, b) = (random(), random());
+
+        assert_eq!(Fp2::new(a.norm(), Fp::zero()), &a * &a.conjugate());
+        assert_eq!(Fp2::new(a.trace(), Fp::zero()), &a + &a.conjugate());
+        // The norm is multiplicative and the trace additive
+        assert_eq!((&a * &b).norm(), a.norm() * b.norm());
+        assert_eq!((&a + &b).trace(), a.trace() + b.trace());
+
+        // a^(p-1) = ā/a has norm one, as does u
+        let unit = &a.conjugate() * &a.inv();
+        assert!(unit.is_norm_one());
+        assert!(Fp2::new(Fp::zero(), Fp::one()).is_norm_one());
+        assert!(!Fp2::new(Fp::new(2u32.into()), Fp::zero()).is_norm_one());
+    }
+
+    #[test]
+    fn test_frobenius_map() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = Fp2::new(
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+            );
+            let b = Fp2::new(
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+            );
+            // Applying the Frobenius twice is the identity on Fp2
+            assert_eq!(a.frobenius_map(1).frobenius_map(1), a);
+            assert_eq!(a.frobenius_map(2), a);
+            assert_eq!(a.frobenius_map(3), a.conjugate());
+            // It is a ring homomorphism
+            assert_eq!(
+                (&a * &b).frobenius_map(1),
+                &a.frobenius_map(1) * &b.frobenius_map(1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        let mut rng = rand::thread_rng();
+        let a = Fp2::new(
+            Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+            Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+        );
+        assert_eq!(a.pow(&Fp2::order_minus_one()), Fp2::one());
+        assert_eq!(a.pow(&0u32.to_biguint().unwrap()), Fp2::one());
+        assert_eq!(a.pow_vartime(&[]), Fp2::one());
+        assert_eq!(a.pow_vartime(&[5]), &a.square().square() * &a);
+        // a^p is the Frobenius map
+        assert_eq!(a.pow(&P), a.frobenius_map(1));
+        // A non-square has a^((p² - 1)/2) = -1
+        let non_residue = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        assert!(!non_residue.is_quadratic_residue());
+        assert_eq!(non_residue.pow(&(Fp2::order_minus_one() >> 1)), -Fp2::one());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = Fp2::new(
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+                Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+            );
+            let sq = &a * &a;
+            let root = sq.sqrt().unwrap();
+            assert!(root == a || root == -a);
+        }
+        // u + 9 is the non-residue used to build Fp6
+        let xi = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        assert_eq!(xi.sqrt(), None);
+
+        assert_eq!(Fp2::one().sqrt(), Some(Fp2::one()));
+        assert_eq!(Fp2::zero().sqrt(), Some(Fp2::zero()));
+        // √-1 = u
+        let u = Fp2::new(Fp::zero(), Fp::one());
+        assert_eq!((-Fp2::one()).sqrt(), Some(u.clone()));
+        // u = ((1 + u) / √2)², with √2 ∈ Fp since p ≡ 7 (mod 8)
+        let root = u.sqrt().unwrap();
+        assert_eq!(&root * &root, u);
+    }
+
+    #[test]
+    fn test_display() {
+        let a = Fp2::new(Fp::new(7u32.into()), -Fp::one());
+        assert_eq!(
+            a.to_string(),
+            "7 + 21888242871839275222246405745257275088696311157297823662689037894645226208582*u"
+        );
+        assert_eq!(
+            format!("{:#x}", Fp2::new(Fp::zero(), Fp::new(255u32.into()))),
+            concat!(
+                "0x0000000000000000000000000000000000000000000000000000000000000000 + ",
+                "0x00000000000000000000000000000000000000000000000000000000000000ff*u"
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let a = Fp2::new(Fp::new(7u32.into()), Fp::new(255u32.into()));
+        assert_eq!("7,255".parse::<Fp2>(), Ok(a.clone()));
+        assert_eq!("0x7, 0xff".parse::<Fp2>(), Ok(a));
+        assert_eq!("7".parse::<Fp2>(), Err(ParseFpError::InvalidComponentCount));
+        assert_eq!(
+            "1,2,3".parse::<Fp2>(),
+            Err(ParseFpError::InvalidComponentCount)
+        );
+        assert_eq!("1,".parse::<Fp2>(), Err(ParseFpError::Empty));
+    }
+
+    #[test]
+    fn test_batch_inv() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fp2> = (0..10)
+            .map(|_| {
+                Fp2::new(
+                    Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+                    Fp::new(rng.gen::<u128>().to_biguint().unwrap()),
+                )
+            })
+            .collect();
+        values[3] = Fp2::zero();
+        let inverses = batch_inv(&values);
+        for (v, inv) in values.iter().zip(inverses.iter()) {
+            if v.is_zero() {
+                assert!(inv.is_zero());
+            } else {
+                assert_eq!(v * inv, Fp2::one());
+            }
+        }
+        assert!(batch_inv(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_mul_by_fp() {
+        let mut rng = rand::thread_rng();
+        let a = Fp2::new(
+            Fp::new(rng.gen::<u128>().into()),
+            Fp::new(rng.gen::<u128>().into()),
+        );
+        let s = Fp::new(rng.gen::<u128>().into());
+        let s2 = Fp2::new(s, Fp::zero());
+        assert_eq!(a.mul_by_fp(&s), &a * &s2);
+        assert_eq!(a.mul_by_fp(&s), &s2 * &a);
+    }
+
     #[test]
     fn test_field_laws() {
         let a = Fp2::new(Fp::new(3u32.into()), Fp::new(5u32.into()));
@@ -229,4 +597,19 @@ mod tests {
         let dist2 = &(&a * &b) + &(&a * &c);
         assert_eq!(dist1, dist2);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = Fp2::new(Fp::new(1u32.into()), Fp::new(0xffu32.into()));
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"c0":"0x0000000000000000000000000000000000000000000000000000000000000001","#,
+                r#""c1":"0x00000000000000000000000000000000000000000000000000000000000000ff"}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<Fp2>(&json).unwrap(), a);
+    }
 }
@@ -1,10 +1,15 @@
+use crate::constants::{FROBENIUS_COEFF_FP6_C1, FROBENIUS_COEFF_FP6_C2};
+use crate::error::Error;
+use crate::fp::Fp;
 use crate::fp2::Fp2;
-use std::ops::{Add, Mul, Neg, Sub};
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
 
 /// Fp6 represents the cubic extension Fp6 = Fp2[v] / (v³ - (u+9))
 /// An element is represented as c0 + c1*v + c2*v²
 /// where v³ = u+9 (the non-residue in Fp2)
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fp6 {
     pub c0: Fp2,
     pub c1: Fp2,
@@ -12,7 +17,7 @@ pub struct Fp6 {
 }
 
 impl Fp6 {
-    pub fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Self {
+    pub const fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Self {
         Fp6 { c0, c1, c2 }
     }
 
@@ -32,10 +37,15 @@ impl Fp6 {
         }
     }
 
+    /// A uniformly random element, see `Fp::random`
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Fp6::new(Fp2::random(rng), Fp2::random(rng), Fp2::random(rng))
+    }
+
     /// Non-residue: u+9 in Fp2
     fn non_residue() -> Fp2 {
-        use crate::fp::Fp;
-        Fp2::new(Fp::new(9u32.into()), Fp::new(1u32.into()))
+        Fp2::new(Fp::from(9u32), Fp::from(1u32))
     }
 
     /// Multiply by non-residue
@@ -43,13 +53,97 @@ impl Fp6 {
         a * &Self::non_residue()
     }
 
+    /// Frobenius endomorphism: a^(p^power)
+    /// σ(c0 + c1*v + c2*v²) = σ(c0) + σ(c1)*v^p + σ(c2)*v^(2p)
+    /// where v^(p^k) = ξ^((p^k - 1) / 3) * v. The coefficients for
+    /// k = 0..6 are precomputed in `constants`; σ has order 6, so `power`
+    /// is taken mod 6.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fp6 {
+            c0: self.c0.frobenius_map(power),
+            c1: &self.c1.frobenius_map(power) * &FROBENIUS_COEFF_FP6_C1[power % 6],
+            c2: &self.c2.frobenius_map(power) * &FROBENIUS_COEFF_FP6_C2[power % 6],
+        }
+    }
+
+    /// Squaring with the Chung-Hasan SQR2 formula: 2 Fp2 multiplications
+    /// and 3 squarings instead of the 6 multiplications of `Mul`
+    pub fn square(&self) -> Self {
+        let s0 = self.c0.square();
+        let ab = &self.c0 * &self.c1;
+        let s1 = &ab + &ab;
+        let s2 = (&(&self.c0 - &self.c1) + &self.c2).square();
+        let bc = &self.c1 * &self.c2;
+        let s3 = &bc + &bc;
+        let s4 = self.c2.square();
+
+        Fp6 {
+            c0: &s0 + &Self::mul_by_non_residue(&s3),
+            c1: &s1 + &Self::mul_by_non_residue(&s4),
+            c2: &(&(&s1 + &s2) + &s3) - &s0 - s4,
+        }
+    }
+
+    /// Multiply every coefficient by an Fp2 element
+    pub fn mul_by_fp2(&self, scalar: &Fp2) -> Self {
+        Fp6 {
+            c0: &self.c0 * scalar,
+            c1: &self.c1 * scalar,
+            c2: &self.c2 * scalar,
+        }
+    }
+
+    /// Multiply every coefficient by a base field element
+    pub fn mul_by_fp(&self, scalar: &Fp) -> Self {
+        Fp6 {
+            c0: self.c0.mul_by_fp(scalar),
+            c1: self.c1.mul_by_fp(scalar),
+            c2: self.c2.mul_by_fp(scalar),
+        }
+    }
+
+    /// Multiply by a sparse element b0 + b1*v (5 Fp2 multiplications
+    /// instead of 6 for a dense Karatsuba product)
+    pub fn mul_by_01(&self, b0: &Fp2, b1: &Fp2) -> Self {
+        let a_a = &self.c0 * b0;
+        let b_b = &self.c1 * b1;
+
+        let c0 = &Self::mul_by_non_residue(&(&self.c2 * b1)) + &a_a;
+        let c1 = &(&(&self.c0 + &self.c1) * &(b0 + b1)) - &a_a - b_b.clone();
+        let c2 = &(&self.c2 * b0) + &b_b;
+
+        Fp6 { c0, c1, c2 }
+    }
+
+    /// Multiply by a sparse element b1*v (3 Fp2 multiplications)
+    pub fn mul_by_1(&self, b1: &Fp2) -> Self {
+        Fp6 {
+            c0: Self::mul_by_non_residue(&(&self.c2 * b1)),
+            c1: &self.c0 * b1,
+            c2: &self.c1 * b1,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    /// The inverse, or `Error::ZeroInverse` for zero
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.inv())
+    }
+
+    /// Panics if `self` is zero; `try_inv` returns an error instead.
     pub fn inv(&self) -> Self {
         // Using the formula from "Implementing Cryptographic Pairings"
         let _nr = Self::non_residue();
 
-        let c0 = &(&self.c0 * &self.c0) - &Self::mul_by_non_residue(&(&self.c1 * &self.c2));
-        let c1 = &Self::mul_by_non_residue(&(&self.c2 * &self.c2)) - &(&self.c0 * &self.c1);
-        let c2 = &(&self.c1 * &self.c1) - &(&self.c0 * &self.c2);
+        let c0 = &self.c0.square() - &Self::mul_by_non_residue(&(&self.c1 * &self.c2));
+        let c1 = &Self::mul_by_non_residue(&self.c2.square()) - &(&self.c0 * &self.c1);
+        let c2 = &self.c1.square() - &(&self.c0 * &self.c2);
 
         let t = &(&self.c2 * &Self::mul_by_non_residue(&c1))
             + &(&self.c1 * &Self::mul_by_non_residue(&c2))
@@ -64,6 +158,22 @@ impl Fp6 {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fp6 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
+/// The default is zero
+impl Default for Fp6 {
+    fn default() -> Self {
+        Fp6::zero()
+    }
+}
+
 impl Add for Fp6 {
     type Output = Fp6;
     fn add(self, rhs: Fp6) -> Fp6 {
@@ -75,7 +185,7 @@ impl Add for Fp6 {
     }
 }
 
-impl<'a, 'b> Add<&'b Fp6> for &'a Fp6 {
+impl<'b> Add<&'b Fp6> for &Fp6 {
     type Output = Fp6;
     fn add(self, rhs: &'b Fp6) -> Fp6 {
         Fp6 {
@@ -97,7 +207,7 @@ impl Sub for Fp6 {
     }
 }
 
-impl<'a, 'b> Sub<&'b Fp6> for &'a Fp6 {
+impl<'b> Sub<&'b Fp6> for &Fp6 {
     type Output = Fp6;
     fn sub(self, rhs: &'b Fp6) -> Fp6 {
         Fp6 {
@@ -128,7 +238,7 @@ impl Mul for Fp6 {
     }
 }
 
-impl<'a, 'b> Mul<&'b Fp6> for &'a Fp6 {
+impl<'b> Mul<&'b Fp6> for &Fp6 {
     type Output = Fp6;
     fn mul(self, rhs: &'b Fp6) -> Fp6 {
         // Karatsuba multiplication
@@ -159,10 +269,38 @@ impl Neg for Fp6 {
     }
 }
 
+impl Fp6 {
+    fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        fmt_fp2: fn(&Fp2, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        f.write_str("(")?;
+        fmt_fp2(&self.c0, f)?;
+        f.write_str(") + (")?;
+        fmt_fp2(&self.c1, f)?;
+        f.write_str(")*v + (")?;
+        fmt_fp2(&self.c2, f)?;
+        f.write_str(")*v^2")
+    }
+}
+
+/// Displayed as "(c0) + (c1)*v + (c2)*v^2"
+impl fmt::Display for Fp6 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, <Fp2 as fmt::Display>::fmt)
+    }
+}
+
+impl fmt::LowerHex for Fp6 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, <Fp2 as fmt::LowerHex>::fmt)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fp::Fp;
 
     #[test]
     fn test_basic_ops() {
@@ -178,7 +316,97 @@ mod tests {
         );
 
         let sum = &a + &b;
-        assert_eq!(sum.c0, Fp2::new(Fp::new(8u32.into()), Fp::new(10u32.into())));
+        assert_eq!(
+            sum.c0,
+            Fp2::new(Fp::new(8u32.into()), Fp::new(10u32.into()))
+        );
+    }
+
+    fn random_fp6(rng: &mut impl rand::Rng) -> Fp6 {
+        let mut fp2 = || {
+            Fp2::new(
+                Fp::new(rng.gen::<u128>().into()),
+                Fp::new(rng.gen::<u128>().into()),
+            )
+        };
+        Fp6::new(fp2(), fp2(), fp2())
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp6(&mut rng);
+        let s2 = random_fp6(&mut rng).c0;
+        let s = s2.c0;
+
+        let embed = |c: Fp2| Fp6::new(c, Fp2::zero(), Fp2::zero());
+        assert_eq!(a.mul_by_fp2(&s2), &a * &embed(s2.clone()));
+        assert_eq!(a.mul_by_fp2(&s2), &embed(s2.clone()) * &a);
+        let s_fp2 = Fp2::new(s, Fp::zero());
+        assert_eq!(a.mul_by_fp(&s), &a * &embed(s_fp2.clone()));
+        assert_eq!(a.mul_by_fp(&s), &embed(s_fp2) * &a);
+    }
+
+    #[test]
+    fn test_square() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = random_fp6(&mut rng);
+            assert_eq!(a.square(), &a * &a);
+            assert_eq!(a.c0.square(), &a.c0 * &a.c0);
+        }
+    }
+
+    #[test]
+    fn test_sparse_mul() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp6(&mut rng);
+        let b = random_fp6(&mut rng);
+
+        let b01 = Fp6::new(b.c0.clone(), b.c1.clone(), Fp2::zero());
+        assert_eq!(a.mul_by_01(&b.c0, &b.c1), &a * &b01);
+
+        let b1 = Fp6::new(Fp2::zero(), b.c1.clone(), Fp2::zero());
+        assert_eq!(a.mul_by_1(&b.c1), &a * &b1);
+    }
+
+    #[test]
+    fn test_frobenius_map() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let a = random_fp6(&mut rng);
+            let b = random_fp6(&mut rng);
+
+            // σ^6 is the identity on Fp6
+            let mut x = a.clone();
+            for _ in 0..6 {
+                x = x.frobenius_map(1);
+            }
+            assert_eq!(x, a);
+
+            // σ^k agrees with k applications of σ
+            let mut x = a.clone();
+            for k in 1..6 {
+                x = x.frobenius_map(1);
+                assert_eq!(a.frobenius_map(k), x);
+            }
+
+            // It is a ring homomorphism
+            assert_eq!(
+                (&a * &b).frobenius_map(1),
+                &a.frobenius_map(1) * &b.frobenius_map(1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let a = Fp6::new(
+            Fp2::one(),
+            Fp2::zero(),
+            Fp2::new(Fp::new(2u32.into()), Fp::new(3u32.into())),
+        );
+        assert_eq!(a.to_string(), "(1 + 0*u) + (0 + 0*u)*v + (2 + 3*u)*v^2");
     }
 
     #[test]
@@ -194,4 +422,12 @@ mod tests {
         assert_eq!(prod.c1, Fp2::zero());
         assert_eq!(prod.c2, Fp2::zero());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = random_fp6(&mut rand::thread_rng());
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(serde_json::from_str::<Fp6>(&json).unwrap(), a);
+    }
 }
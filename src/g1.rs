@@ -1,14 +1,104 @@
-use crate::fp::Fp;
+#[cfg(feature = "alloc")]
+use crate::fp::batch_inv;
+use crate::fp::{Fp, FpError};
+#[cfg(feature = "alloc")]
+use crate::hash_to_field::hash_to_fp;
+#[cfg(feature = "alloc")]
+use crate::scalar::Scalar;
+#[cfg(feature = "alloc")]
+use crate::GROUP_ORDER;
+use crate::GROUP_ORDER_LIMBS;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops::Mul;
+use core::ops::{Add, AddAssign, Neg, Sub};
+#[cfg(feature = "alloc")]
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(feature = "alloc")]
 use num_traits::Zero;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// β, a primitive cube root of unity in Fp. The endomorphism
+/// (x, y) ↦ (βx, y) acts on G1 as multiplication by GLV_LAMBDA.
+const GLV_BETA: Fp = Fp::from_dec(b"2203960485148121921418603742825762020974279258880205651966");
+
+/// Constants of the Shallue-van de Woestijne map (RFC 9380 section 6.6.1)
+/// for y² = x³ + 3 with Z = 1: c1 = g(Z), c2 = -Z/2,
+/// c3 = sqrt(-g(Z)·3Z²) with sgn0(c3) = 0 and c4 = -4g(Z) / 3Z²
+const SVDW_C1: Fp = Fp::from_dec(b"4");
+const SVDW_C2: Fp =
+    Fp::from_dec(b"10944121435919637611123202872628637544348155578648911831344518947322613104291");
+const SVDW_C3: Fp = Fp::from_dec(b"8815841940592487685674414971303048083897117035520822607866");
+const SVDW_C4: Fp =
+    Fp::from_dec(b"7296080957279758407415468581752425029565437052432607887563012631548408736189");
+
+#[cfg(feature = "alloc")]
+lazy_static::lazy_static! {
+    /// λ = 0xb3c4d79d41a917585bfc41088d8daaa78b17ea66b99c90dd, a cube root
+    /// of unity mod r
+    static ref GLV_LAMBDA: BigUint = BigUint::parse_bytes(
+        b"4407920970296243842393367215006156084916469457145843978461",
+        10
+    )
+    .unwrap();
+
+    /// Short basis (a1, b1), (a2, b2) of the lattice of (x, y) with
+    /// x + y·λ ≡ 0 (mod r), from the extended Euclidean algorithm on (r, λ)
+    static ref GLV_BASIS: [(BigInt, BigInt); 2] = {
+        let int = |s: &[u8]| BigInt::parse_bytes(s, 10).unwrap();
+        [
+            (
+                int(b"9931322734385697763"),
+                int(b"-147946756881789319000765030803803410728"),
+            ),
+            (
+                int(b"147946756881789319010696353538189108491"),
+                int(b"9931322734385697763"),
+            ),
+        ]
+    };
+
+    /// Babai rounding constants round(b2·2^256 / r) and round(-b1·2^256 / r)
+    static ref GLV_ROUNDING: [BigInt; 2] = [
+        BigInt::parse_bytes(b"52538187511802934231", 10).unwrap(),
+        BigInt::parse_bytes(b"782660544089080853078787955015628534158", 10).unwrap(),
+    ];
+
+    /// Fixed-base table of the generator with 4-bit windows (960 affine
+    /// points), built on first use, see `G1::mul_gen`
+    pub static ref G1_GENERATOR_TABLE: G1FixedBase = G1::generator().precompute_fixed_base(4);
+}
+
+/// A G1 point in Jacobian coordinates (X:Y:Z), representing the affine
+/// point (X/Z², Y/Z³), with Z = 0 for the point at infinity.
+///
+/// The coordinate fields are deprecated outside this crate: build points
+/// with `from_affine`, which checks them, or the explicit
+/// `from_affine_unchecked`, so invalid points do not slip in unnoticed,
+/// and read them through `to_affine`.
+#[derive(Clone, Copy, Debug)]
 pub struct G1 {
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub x: Fp,
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub y: Fp,
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub z: Fp,
 }
 
 impl G1 {
+    /// The standard BN254 G1 generator, the affine point (1, 2)
+    pub fn generator() -> Self {
+        Self {
+            x: Fp::one(),
+            y: Fp::from(2u32),
+            z: Fp::one(),
+        }
+    }
+
     pub fn infinity() -> Self {
         Self {
             x: Fp::zero(),
@@ -17,8 +107,37 @@ impl G1 {
         }
     }
 
+    /// The affine point (x, y), checked to be on the curve and in the
+    /// prime-order subgroup, see `validate`
+    pub fn from_affine(x: Fp, y: Fp) -> Result<Self, PointError> {
+        let p = Self::from_affine_unchecked(x, y);
+        p.validate()?;
+        Ok(p)
+    }
+
+    /// The affine point (x, y) without any checks. Arithmetic on points
+    /// off the curve gives meaningless results, so this is for inputs
+    /// already known to be valid and for tests of the validation itself.
+    pub fn from_affine_unchecked(x: Fp, y: Fp) -> Self {
+        Self { x, y, z: Fp::one() }
+    }
+
     pub fn is_infinity(&self) -> bool {
-        self.z.n.is_zero()
+        self.z.is_zero()
+    }
+
+    /// Whether the two points are equal as affine points, whatever their
+    /// Jacobian scaling: X1·Z2² = X2·Z1² and Y1·Z2³ = Y2·Z1³, which needs
+    /// no inversion. This is what `==` uses.
+    pub fn eq_affine(&self, other: &G1) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => return true,
+            (false, false) => {}
+            _ => return false,
+        }
+        let z1z1 = self.z * self.z;
+        let z2z2 = other.z * other.z;
+        self.x * z2z2 == other.x * z1z1 && self.y * z2z2 * other.z == other.y * z1z1 * self.z
     }
 
     pub fn to_affine(&self) -> (Fp, Fp) {
@@ -26,40 +145,167 @@ impl G1 {
             return (Fp::zero(), Fp::zero());
         }
         let z_inv = self.z.inv();
-        let z2 = z_inv.clone() * z_inv.clone();
-        let z3 = z2.clone() * z_inv;
-        let x_aff = self.x.clone() * z2;
-        let y_aff = self.y.clone() * z3;
+        let z2 = z_inv * z_inv;
+        let z3 = z2 * z_inv;
+        let x_aff = self.x * z2;
+        let y_aff = self.y * z3;
         (x_aff, y_aff)
     }
 
+    /// Converts many points to affine coordinates with a single field
+    /// inversion, see `fp::batch_inv`. As with `to_affine`, points at
+    /// infinity map to (0, 0).
+    #[cfg(feature = "alloc")]
+    pub fn batch_normalize(points: &[G1]) -> Vec<(Fp, Fp)> {
+        let zs: Vec<Fp> = points.iter().map(|p| p.z).collect();
+        batch_inv(&zs)
+            .into_iter()
+            .zip(points.iter())
+            .map(|(z_inv, p)| {
+                if p.is_infinity() {
+                    return (Fp::zero(), Fp::zero());
+                }
+                let z2 = z_inv * z_inv;
+                let z3 = z2 * z_inv;
+                (p.x * z2, p.y * z3)
+            })
+            .collect()
+    }
+
+    /// Converts to homogeneous projective coordinates, see `G1Projective`
+    pub fn to_projective(&self) -> G1Projective {
+        (*self).into()
+    }
+
     pub fn is_on_curve(&self) -> bool {
         if self.is_infinity() {
             return true;
         }
         let (x, y) = self.to_affine();
-        y.clone() * y.clone() == x.clone() * x.clone() * x.clone() + Fp::new(3u32.into())
+        y * y == curve_rhs(&x)
+    }
+
+    /// Check membership in the prime-order subgroup by computing [r]P.
+    ///
+    /// An externally supplied point should only be trusted once both
+    /// `is_on_curve` and `is_in_subgroup` pass; `validate` runs both.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.mul_limbs(&GROUP_ORDER_LIMBS).is_infinity()
+    }
+
+    /// Whether the point has no component of order dividing the cofactor,
+    /// i.e. lies in the prime-order subgroup. Same as `is_in_subgroup`.
+    ///
+    /// `is_on_curve` alone does not imply this when the cofactor h is
+    /// greater than 1: such a curve also has points of order dividing h,
+    /// and protocols that skip the check can leak secrets through them (the
+    /// small-subgroup attack). G1 has h = 1, so an on-curve G1 point is
+    /// always torsion-free; `G2` has h > 1 and is where the check matters.
+    pub fn is_torsion_free(&self) -> bool {
+        self.is_in_subgroup()
+    }
+
+    /// Subgroup check using the cofactor. G1 has cofactor h = 1, so every
+    /// point on the curve lies in the prime-order subgroup and this
+    /// reduces to the curve equation.
+    pub fn is_in_subgroup_fast(&self) -> bool {
+        self.is_on_curve()
+    }
+
+    /// Maps a curve point into the prime-order subgroup. G1 has cofactor
+    /// h = 1, so this is the identity map; it exists to mirror
+    /// `G2::clear_cofactor`.
+    pub fn clear_cofactor(&self) -> Self {
+        debug_assert!(
+            self.is_on_curve(),
+            "G1::clear_cofactor: point is not on the curve"
+        );
+        *self
+    }
+
+    /// Compressed encoding used by arkworks' `ark-bn254` (`CanonicalSerialize`
+    /// with compression): x as 32 bytes little-endian, with the sign and
+    /// infinity flags in the two most significant bits of the last byte.
+    /// The flags have the same meaning as in `G1Affine::to_compressed`, so
+    /// this is that encoding byte-reversed. EIP-196 has no compressed form
+    /// and writes both coordinates big-endian.
+    pub fn to_ark_bytes(&self) -> [u8; 32] {
+        let mut out = G1Affine::from(*self).to_compressed();
+        out.reverse();
+        out
+    }
+
+    /// Decodes the arkworks compressed encoding, see `to_ark_bytes`
+    pub fn from_ark_bytes(bytes: &[u8; 32]) -> Result<G1, DecodeError> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        G1Affine::from_compressed(&bytes).map(G1::from)
+    }
+
+    /// Uncompressed encoding used by bellman's BN254 backend (`pairing_ce`):
+    /// x || y, each 32 bytes big-endian, as in `G1Affine::to_uncompressed`,
+    /// except that infinity is all zeros with the infinity flag set in the
+    /// first byte instead of plain (0, 0)
+    pub fn to_bellman_bytes(&self) -> [u8; 64] {
+        if self.is_infinity() {
+            let mut out = [0u8; 64];
+            out[0] = COMPRESSED_INFINITY_FLAG;
+            return out;
+        }
+        G1Affine::from(*self).to_uncompressed()
+    }
+
+    /// Decodes the bellman uncompressed encoding, see `to_bellman_bytes`.
+    /// A set compression flag or a malformed infinity is rejected, and so
+    /// is (0, 0), which is not on the curve.
+    pub fn from_bellman_bytes(bytes: &[u8; 64]) -> Result<G1, DecodeError> {
+        if bytes[0] & COMPRESSED_SIGN_FLAG != 0 {
+            return Err(DecodeError::InvalidCompressedFlag);
+        }
+        if bytes[0] & COMPRESSED_INFINITY_FLAG != 0 {
+            if bytes[0] != COMPRESSED_INFINITY_FLAG || bytes[1..].iter().any(|&b| b != 0) {
+                return Err(DecodeError::InvalidCompressedFlag);
+            }
+            return Ok(G1::infinity());
+        }
+
+        Ok(G1::from_affine(
+            Fp::from_bytes_be(&bytes[..32])?,
+            Fp::from_bytes_be(&bytes[32..])?,
+        )?)
+    }
+
+    /// Checks the curve equation and subgroup membership
+    pub fn validate(&self) -> Result<(), PointError> {
+        if !self.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+        if !self.is_in_subgroup() {
+            return Err(PointError::NotInSubgroup);
+        }
+        Ok(())
     }
 
     /// Doubling in Jacobian coordinates
     pub fn double(&self) -> Self {
         if self.is_infinity() {
-            return self.clone();
+            return *self;
         }
 
-        let xx = self.x.clone() * self.x.clone();
-        let yy = self.y.clone() * self.y.clone();
-        let yyyy = yy.clone() * yy.clone();
-        let s = ((self.x.clone() + yy.clone()) * (self.x.clone() + yy.clone())
-            - xx.clone()
-            - yyyy.clone())
-            + ((self.x.clone() + yy.clone()) * (self.x.clone() + yy.clone())
-                - xx.clone()
-                - yyyy.clone()); // 2*S
-        let m = xx.clone() + xx.clone() + xx.clone(); // 3*XX
-        let x3 = m.clone() * m.clone() - s.clone() - s.clone();
-        let y3 = m * (s - x3.clone()) - yyyy.clone() - yyyy.clone() - yyyy.clone() - yyyy.clone(); // 8*YYYY
-        let z3 = (self.y.clone() * self.z.clone()) + (self.y.clone() * self.z.clone()); // 2*Y1*Z1
+        let xx = self.x * self.x;
+        let yy = self.y * self.y;
+        let yyyy = yy * yy;
+        let s = ((self.x + yy) * (self.x + yy) - xx - yyyy)
+            + ((self.x + yy) * (self.x + yy) - xx - yyyy); // 2*S
+        let m = xx + xx + xx; // 3*XX
+        let x3 = m * m - s - s;
+        let yyyy8 = {
+            let yyyy2 = yyyy + yyyy;
+            let yyyy4 = yyyy2 + yyyy2;
+            yyyy4 + yyyy4
+        };
+        let y3 = m * (s - x3) - yyyy8; // 8*YYYY
+        let z3 = (self.y * self.z) + (self.y * self.z); // 2*Y1*Z1
         Self {
             x: x3,
             y: y3,
@@ -70,18 +316,18 @@ impl G1 {
     /// Addition in Jacobian coordinates
     pub fn add(&self, other: &Self) -> Self {
         if self.is_infinity() {
-            return other.clone();
+            return *other;
         }
         if other.is_infinity() {
-            return self.clone();
+            return *self;
         }
 
-        let z1z1 = self.z.clone() * self.z.clone();
-        let z2z2 = other.z.clone() * other.z.clone();
-        let u1 = self.x.clone() * z2z2.clone();
-        let u2 = other.x.clone() * z1z1.clone();
-        let s1 = self.y.clone() * z2z2.clone() * other.z.clone();
-        let s2 = other.y.clone() * z1z1.clone() * self.z.clone();
+        let z1z1 = self.z * self.z;
+        let z2z2 = other.z * other.z;
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * z2z2 * other.z;
+        let s2 = other.y * z1z1 * self.z;
 
         if u1 == u2 {
             if s1 == s2 {
@@ -91,18 +337,95 @@ impl G1 {
             }
         }
 
-        let h = u2 - u1.clone();
-        let i = (h.clone() + h.clone()) * (h.clone() + h.clone());
-        let j = h.clone() * i.clone();
-        let r = (s2.clone() - s1.clone()) + (s2 - s1.clone());
-        let v = u1.clone() * i;
+        let h = u2 - u1;
+        let i = (h + h) * (h + h);
+        let j = h * i;
+        let r = (s2 - s1) + (s2 - s1);
+        let v = u1 * i;
+
+        let x3 = r * r - j - v - v;
+        let y3 = r * (v - x3) - s1 * j - s1 * j;
+        let z3 = ((self.z + other.z) * (self.z + other.z) - z1z1 - z2z2) * h;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Mixed addition of an affine point (madd-2007-bl), saving the
+    /// multiplications by Z2 that `add` needs
+    pub fn add_affine(&self, other: &G1Affine) -> Self {
+        if other.infinity {
+            return *self;
+        }
+        if self.is_infinity() {
+            return other.clone().into();
+        }
+
+        let z1z1 = self.z * self.z;
+        let u2 = other.x * z1z1;
+        let s2 = other.y * z1z1 * self.z;
+
+        if self.x == u2 {
+            if self.y == s2 {
+                return self.double();
+            } else {
+                return Self::infinity();
+            }
+        }
+
+        let h = u2 - self.x;
+        let hh = h * h;
+        let i = hh + hh + hh + hh;
+        let j = h * i;
+        let r = (s2 - self.y) + (s2 - self.y);
+        let v = self.x * i;
+
+        let x3 = r * r - j - v - v;
+        let y3 = r * (v - x3) - self.y * j - self.y * j;
+        let z3 = (self.z + h) * (self.z + h) - z1z1 - hh;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Sum of two affine points as a Jacobian point.
+    ///
+    /// With both Z coordinates equal to 1 this is the "mmadd-2007-bl"
+    /// formula from the Explicit-Formulas Database, 4M + 2S against 7M + 4S
+    /// for `add_affine`. The affine chord formula λ = (y2 - y1)/(x2 - x1)
+    /// would need a field inversion, which costs far more than the
+    /// multiplications saved, so the result is left in Jacobian form.
+    pub fn add_affine_points(p: &G1Affine, q: &G1Affine) -> Self {
+        if p.infinity {
+            return q.clone().into();
+        }
+        if q.infinity {
+            return p.clone().into();
+        }
+        if p.x == q.x {
+            if p.y == q.y {
+                return G1::from(p.clone()).double();
+            } else {
+                return Self::infinity();
+            }
+        }
+
+        let h = q.x - p.x;
+        let hh = h * h;
+        let i = hh + hh + hh + hh;
+        let j = h * i;
+        let r = (q.y - p.y) + (q.y - p.y);
+        let v = p.x * i;
 
-        let x3 = r.clone() * r.clone() - j.clone() - v.clone() - v.clone();
-        let y3 = r * (v - x3.clone()) - s1.clone() * j.clone() - s1.clone() * j.clone();
-        let z3 = ((self.z.clone() + other.z.clone()).clone() * (self.z.clone() + other.z.clone())
-            - z1z1
-            - z2z2)
-            * h;
+        let x3 = r * r - j - v - v;
+        let y3 = r * (v - x3) - p.y * j - p.y * j;
+        let z3 = h + h;
 
         Self {
             x: x3,
@@ -111,103 +434,1948 @@ impl G1 {
         }
     }
 
-    /// Scalar multiplication using double-and-add
-    pub fn mul_u128(&self, mut scalar: u128) -> Self {
+    /// Scalar multiplication with a Montgomery ladder
+    ///
+    /// Every bit of the scalar, padded to 256 bits, costs one addition and
+    /// one doubling, and the two running points are swapped with
+    /// `select` instead of a branch. The sequence of operations
+    /// therefore does not depend on the scalar's bits. This is only an
+    /// algorithmic guarantee: `add` and `double` still branch on special
+    /// points and nothing here is verified at the hardware level. When the
+    /// scalar is public, `mul_scalar_glv` and `mul_scalar_wnaf` are faster.
+    #[cfg(feature = "alloc")]
+    pub fn mul_scalar(&self, scalar: &BigUint) -> Self {
+        self.ladder(scalar.bits().max(256), |i| scalar.bit(i))
+    }
+
+    /// `mul_scalar` for a scalar given as little-endian 64-bit limbs, as
+    /// `Fp::pow_vartime` takes its exponent. Needs no allocation.
+    pub fn mul_limbs(&self, scalar: &[u64; 4]) -> Self {
+        self.ladder(256, |i| (scalar[i as usize / 64] >> (i % 64)) & 1 == 1)
+    }
+
+    /// The Montgomery ladder of `mul_scalar` over the low `bits` bits of
+    /// a scalar
+    fn ladder(&self, bits: u64, bit: impl Fn(u64) -> bool) -> Self {
+        let mut r0 = Self::infinity();
+        let mut r1 = *self;
+
+        // Invariant: r1 = r0 + self
+        for i in (0..bits).rev() {
+            let bit = bit(i);
+            let a = Self::select(&r0, &r1, bit);
+            let b = Self::select(&r1, &r0, bit);
+            let sum = a.add(&b);
+            let double = a.double();
+            r0 = Self::select(&double, &sum, bit);
+            r1 = Self::select(&sum, &double, bit);
+        }
+
+        r0
+    }
+
+    /// Returns `b` if `choice` is set and `a` otherwise, see `Fp::select`
+    pub(crate) fn select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self {
+            x: Fp::select(&a.x, &b.x, choice),
+            y: Fp::select(&a.y, &b.y, choice),
+            z: Fp::select(&a.z, &b.z, choice),
+        }
+    }
+
+    /// A uniformly random point of the group, [k]G for a random scalar k
+    #[cfg(all(feature = "rand", feature = "alloc"))]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Self::generator().mul_scalar_glv(&random_scalar(rng))
+    }
+
+    /// Scalar multiplication by a u128, see `mul_scalar`
+    pub fn mul_u128(&self, scalar: u128) -> Self {
+        self.mul_limbs(&[scalar as u64, (scalar >> 64) as u64, 0, 0])
+    }
+
+    /// Scalar multiplication by the canonical residue of a base field
+    /// element, see `mul_scalar`.
+    ///
+    /// Fp and the scalar field Fr are different fields: p > r, so an Fp
+    /// value s in [r, p) multiplies by s - r, and Fp arithmetic on s does
+    /// not correspond to arithmetic on the scalar. This only means what
+    /// one expects when s < r, and callers must reduce or check that
+    /// themselves. Scalars that are field elements of Fr belong in
+    /// `Scalar` instead.
+    pub fn mul_by_fp(&self, s: &Fp) -> Self {
+        self.mul_limbs(&s.to_u64_le_digits())
+    }
+
+    /// Scalar multiplication using the width-w NAF of the scalar
+    ///
+    /// Precomputes the odd multiples P, 3P, ..., (2^(w-1) - 1)P and then
+    /// performs one doubling per digit and one addition or subtraction per
+    /// nonzero digit, roughly n / (w + 1) additions for an n-bit scalar.
+    /// `window` must be between 2 and 8.
+    #[cfg(feature = "alloc")]
+    pub fn mul_scalar_wnaf(&self, scalar: &BigUint, window: usize) -> Self {
+        let digits = wnaf(scalar, window);
+
+        // table[i] = (2i + 1) * P
+        let double = self.double();
+        let mut table = vec![*self];
+        for i in 1..(1 << (window - 2)) {
+            let next = table[i - 1].add(&double);
+            table.push(next);
+        }
+
+        let mut res = Self::infinity();
+        for &d in digits.iter().rev() {
+            res = res.double();
+            if d > 0 {
+                res = res.add(&table[(d as usize - 1) / 2]);
+            } else if d < 0 {
+                res = res - table[((-d) as usize - 1) / 2];
+            }
+        }
+
+        res
+    }
+
+    /// Variable-time double-and-add over the NAF of the scalar, see
+    /// `to_naf`: one doubling per digit and one addition of P or -P per
+    /// nonzero digit. A random n-bit scalar has about n/2 set bits but
+    /// only about n/3 nonzero NAF digits, so this saves a third of the
+    /// additions of plain double-and-add, roughly 85 of 254 bits' worth.
+    #[cfg(feature = "alloc")]
+    pub fn naf_mul(&self, scalar: &BigUint) -> Self {
+        let neg = -*self;
         let mut res = Self::infinity();
-        let mut base = self.clone();
+        for &d in to_naf(scalar).iter().rev() {
+            res = res.double();
+            match d {
+                1 => res = res.add(self),
+                -1 => res = res.add(&neg),
+                _ => {}
+            }
+        }
+        res
+    }
+
+    /// The GLV endomorphism φ(x, y) = (βx, y), equal to [λ]P on G1.
+    /// On Jacobian coordinates it only scales X.
+    pub fn endomorphism(&self) -> Self {
+        Self {
+            x: self.x * GLV_BETA,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Scalar multiplication using the GLV method: the scalar is split as
+    /// k ≡ k1 + k2·λ (mod r) with |k1|, |k2| < 2^128, and k1·P + k2·φ(P) is
+    /// evaluated with a simultaneous double-and-add, halving the number of
+    /// doublings compared to `mul_scalar`.
+    #[cfg(feature = "alloc")]
+    pub fn mul_scalar_glv(&self, scalar: &BigUint) -> Self {
+        let (k1, k2) = glv_decompose(scalar);
+        let p1 = if k1.sign() == Sign::Minus {
+            -*self
+        } else {
+            *self
+        };
+        let p2 = if k2.sign() == Sign::Minus {
+            -self.endomorphism()
+        } else {
+            self.endomorphism()
+        };
+        let (k1, k2) = (k1.magnitude(), k2.magnitude());
+        let p12 = p1.add(&p2);
 
-        while scalar > 0 {
-            if scalar & 1 == 1 {
-                res = res.add(&base);
+        let mut res = Self::infinity();
+        for i in (0..k1.bits().max(k2.bits())).rev() {
+            res = res.double();
+            match (k1.bit(i), k2.bit(i)) {
+                (true, true) => res = res.add(&p12),
+                (true, false) => res = res.add(&p1),
+                (false, true) => res = res.add(&p2),
+                (false, false) => {}
             }
-            base = base.double();
-            scalar >>= 1;
         }
 
         res
     }
+
+    /// [k]G for the generator G, using the shared `G1_GENERATOR_TABLE`.
+    /// Like `G1FixedBase::mul` this is variable time, so `mul_scalar`
+    /// remains the choice for secret scalars where timing matters.
+    #[cfg(feature = "alloc")]
+    pub fn mul_gen(scalar: &Scalar) -> Self {
+        G1_GENERATOR_TABLE.mul(scalar.as_biguint())
+    }
+
+    /// Precomputes the table of `G1FixedBase` for multiplying this point
+    /// by many scalars. Panics unless `window_bits` is between 1 and 16.
+    #[cfg(feature = "alloc")]
+    pub fn precompute_fixed_base(&self, window_bits: usize) -> G1FixedBase {
+        let points = fixed_base_multiples(self, window_bits, G1::add);
+        let table = G1::batch_normalize(&points)
+            .into_iter()
+            .map(G1Affine::from)
+            .collect();
+        G1FixedBase { table, window_bits }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::fp::Fp;
+/// Multiples of a fixed base point for scalar multiplication without
+/// doublings, see `G1::precompute_fixed_base`
+///
+/// The scalar, reduced mod r, is split into w-bit windows and each nonzero
+/// window contributes one mixed addition of a table entry, so a product
+/// costs at most ⌈256/w⌉ additions. The table holds ⌈256/w⌉·(2^w - 1)
+/// affine points: 960 for w = 4, 8160 for w = 8.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1FixedBase {
+    pub table: Vec<G1Affine>,
+    pub window_bits: usize,
+}
 
-    #[test]
-    fn test_infinity() {
-        let inf = G1::infinity();
-        assert!(inf.is_infinity());
-        assert_eq!(inf.to_affine(), (Fp::zero(), Fp::zero()));
+#[cfg(feature = "alloc")]
+impl G1FixedBase {
+    /// [k]P for the precomputed base P. Variable time: the additions
+    /// performed depend on which windows of the scalar are zero.
+    pub fn mul(&self, scalar: &BigUint) -> G1 {
+        fixed_base_mul(
+            &self.table,
+            self.window_bits,
+            scalar,
+            G1::infinity(),
+            G1::add_affine,
+        )
     }
+}
 
-    #[test]
-    fn test_affine_conversion() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let (x_aff, y_aff) = p.to_affine();
-        assert_eq!(x_aff, Fp::new(3u32.into()));
-        assert_eq!(y_aff, Fp::new(6u32.into()));
+/// A uniformly random scalar below r, by rejection sampling 254-bit values
+/// as in `Fp::random`
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub(crate) fn random_scalar<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> BigUint {
+    loop {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        bytes[0] &= 0x3f;
+        let k = BigUint::from_bytes_be(&bytes);
+        if k < *GROUP_ORDER {
+            return k;
+        }
     }
+}
 
-    #[test]
-    fn test_on_curve_known_point() {
-        let p = G1 {
-            x: Fp::new(1u32.into()),
-            y: Fp::new(2u32.into()),
-            z: Fp::one(),
-        };
-        assert!(p.is_on_curve());
+/// Splits k into (k1, k2) with k ≡ k1 + k2·λ (mod r) and both halves
+/// below 2^128 in absolute value, by Babai rounding against GLV_BASIS
+#[cfg(feature = "alloc")]
+pub(crate) fn glv_decompose(scalar: &BigUint) -> (BigInt, BigInt) {
+    let k = BigInt::from(scalar % &*GROUP_ORDER);
+    let [(a1, b1), (a2, b2)] = &*GLV_BASIS;
+    let c1: BigInt = (&k * &GLV_ROUNDING[0]) >> 256;
+    let c2: BigInt = (&k * &GLV_ROUNDING[1]) >> 256;
+    let k1 = &k - &c1 * a1 - &c2 * a2;
+    let k2 = -(&c1 * b1) - &c2 * b2;
+    (k1, k2)
+}
+
+/// Right-hand side of the curve equation, x³ + 3
+fn curve_rhs(x: &Fp) -> Fp {
+    *x * *x * *x + Fp::from(3u32)
+}
+
+/// The Shallue-van de Woestijne map from RFC 9380 section 6.6.1. It is
+/// defined for every input, with the exceptional case 1 ± c1·u² = 0
+/// handled by inv0 mapping 0 to 0; u = 0 itself maps to (c2, √g(c2)).
+///
+/// A single evaluation is not uniform over G1: it reaches only a fraction
+/// of the points. For a random oracle, map two independent field elements
+/// and add the results as points, which is what `hash_to_g1` does.
+pub fn map_to_g1(u: &Fp) -> G1 {
+    let tv1 = *u * *u * SVDW_C1;
+    let tv2 = Fp::one() + tv1;
+    let tv1 = Fp::one() - tv1;
+    let tv3 = tv1 * tv2;
+    let tv3 = if tv3.is_zero() { tv3 } else { tv3.inv() };
+    let tv4 = *u * tv1 * tv3 * SVDW_C3;
+
+    let x1 = SVDW_C2 - tv4;
+    let x2 = SVDW_C2 + tv4;
+    let x3 = {
+        let t = tv2 * tv2 * tv3;
+        t * t * SVDW_C4 + Fp::one()
+    };
+
+    // At least one of g(x1), g(x2), g(x3) is a square
+    let x = [x1, x2, x3]
+        .into_iter()
+        .find(|x| curve_rhs(x).is_quadratic_residue())
+        .unwrap();
+    let y = curve_rhs(&x).sqrt().unwrap();
+    let y = if u.sgn0() == y.sgn0() { y } else { -y };
+
+    G1 { x, y, z: Fp::one() }
+}
+
+/// Hashes a message to G1 following the random-oracle construction of
+/// RFC 9380 (hash_to_curve) with expand_message_xmd over SHA-256 and the
+/// Shallue-van de Woestijne map, i.e. the suite
+/// BN254G1_XMD:SHA-256_SVDW_RO_. `dst` is the domain separation tag.
+///
+/// BN254 has a = 0, which rules out the simplified SWU map of section 6.6.2
+/// without an isogeny. The G1 cofactor is 1, so no cofactor clearing is
+/// needed.
+#[cfg(feature = "alloc")]
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1 {
+    let u = hash_to_fp(msg, dst, 2);
+    map_to_g1(&u[0]).add(&map_to_g1(&u[1]))
+}
+
+/// The nonuniform encode_to_curve variant of `hash_to_g1`
+/// (BN254G1_XMD:SHA-256_SVDW_NU_): one field element and one map
+/// evaluation. Faster, but the output distribution is not uniform.
+#[cfg(feature = "alloc")]
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> G1 {
+    let u = hash_to_fp(msg, dst, 1);
+    map_to_g1(&u[0])
+}
+
+/// Multi-scalar multiplication ∑ scalars[i] * bases[i] using Pippenger's
+/// bucket method
+///
+/// The scalars are split into c-bit windows with c ≈ log₂(n) / 2. For each
+/// window every base is added to the bucket selected by its digit, and the
+/// buckets are combined with a running sum so that bucket j contributes
+/// j times. Panics if the slices have different lengths.
+#[cfg(feature = "alloc")]
+pub fn multi_scalar_mul(bases: &[G1], scalars: &[BigUint]) -> G1 {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "multi_scalar_mul: {} bases but {} scalars",
+        bases.len(),
+        scalars.len()
+    );
+    if bases.is_empty() {
+        return G1::infinity();
     }
 
-    #[test]
-    fn test_double_vs_add() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let double = p.double();
-        let add = p.add(&p);
-        assert_eq!(double.to_affine(), add.to_affine());
+    let log_n = usize::BITS - bases.len().leading_zeros();
+    let c = (log_n as u64 / 2).max(1);
+    let num_bits = scalars.iter().map(|s| s.bits()).max().unwrap_or(0);
+    let num_windows = num_bits.div_ceil(c);
+
+    let mut res = G1::infinity();
+    for w in (0..num_windows).rev() {
+        for _ in 0..c {
+            res = res.double();
+        }
+
+        // buckets[j] accumulates the bases whose digit in this window is j + 1
+        let mut buckets = vec![G1::infinity(); (1 << c) - 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let digit = (0..c)
+                .filter(|&i| scalar.bit(w * c + i))
+                .fold(0usize, |acc, i| acc | 1 << i);
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].add(base);
+            }
+        }
+
+        // ∑ (j + 1) * buckets[j] via running sums from the top bucket down
+        let mut running = G1::infinity();
+        let mut window_sum = G1::infinity();
+        for bucket in buckets.iter().rev() {
+            running = running.add(bucket);
+            window_sum = window_sum.add(&running);
+        }
+
+        res = res.add(&window_sum);
     }
 
-    #[test]
-    fn test_addition_commutative() {
-        let p1 = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let p2 = G1 {
-            x: Fp::new(5u32.into()),
-            y: Fp::new(1u32.into()),
-            z: Fp::one(),
-        };
-        let sum1 = p1.add(&p2);
-        let sum2 = p2.add(&p1);
-        assert_eq!(sum1.to_affine(), sum2.to_affine());
+    res
+}
+
+/// Reference implementation of `multi_scalar_mul` computing each product
+/// separately
+#[cfg(feature = "alloc")]
+pub fn multi_scalar_mul_naive(bases: &[G1], scalars: &[BigUint]) -> G1 {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "multi_scalar_mul_naive: {} bases but {} scalars",
+        bases.len(),
+        scalars.len()
+    );
+    bases
+        .iter()
+        .zip(scalars)
+        .fold(G1::infinity(), |acc, (base, scalar)| {
+            acc.add(&base.mul_scalar(scalar))
+        })
+}
+
+/// The multiples d·2^(w·i)·P for the windows i = 0..⌈256/w⌉ and the digits
+/// d = 1..2^w - 1, window by window, as the table of `G1FixedBase` and
+/// `G2FixedBase`. Panics unless `window_bits` is between 1 and 16.
+#[cfg(feature = "alloc")]
+pub(crate) fn fixed_base_multiples<P: Clone>(
+    base: &P,
+    window_bits: usize,
+    add: impl Fn(&P, &P) -> P,
+) -> Vec<P> {
+    assert!(
+        (1..=16).contains(&window_bits),
+        "fixed-base window must be between 1 and 16, got {}",
+        window_bits
+    );
+    let digits = (1usize << window_bits) - 1;
+    let num_windows = 256usize.div_ceil(window_bits);
+
+    let mut points = Vec::with_capacity(num_windows * digits);
+    let mut window_base = base.clone();
+    for _ in 0..num_windows {
+        let mut multiple = window_base.clone();
+        for _ in 0..digits {
+            points.push(multiple.clone());
+            multiple = add(&multiple, &window_base);
+        }
+        window_base = multiple;
     }
+    points
+}
 
-    #[test]
-    fn test_scalar_mul_u128() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
+/// [k]P from a table laid out by `fixed_base_multiples` (in affine form):
+/// one mixed addition of the entry for each nonzero w-bit window of k mod r
+#[cfg(feature = "alloc")]
+pub(crate) fn fixed_base_mul<P, A>(
+    table: &[A],
+    window_bits: usize,
+    scalar: &BigUint,
+    identity: P,
+    add_affine: impl Fn(&P, &A) -> P,
+) -> P {
+    let scalar = scalar % &*GROUP_ORDER;
+    let w = window_bits;
+    let digits = (1usize << w) - 1;
+
+    let mut acc = identity;
+    for (i, window) in table.chunks(digits).enumerate() {
+        let digit = (0..w)
+            .filter(|&j| scalar.bit((i * w + j) as u64))
+            .fold(0usize, |acc, j| acc | 1 << j);
+        if digit != 0 {
+            acc = add_affine(&acc, &window[digit - 1]);
+        }
+    }
+    acc
+}
+
+/// Non-adjacent form of a scalar, least significant digit first: digits
+/// in {-1, 0, 1} with no two adjacent ones nonzero. This is the width-2
+/// case of `wnaf`.
+#[cfg(feature = "alloc")]
+pub(crate) fn to_naf(scalar: &BigUint) -> Vec<i8> {
+    wnaf(scalar, 2)
+}
+
+/// Width-w non-adjacent form of a scalar, least significant digit first
+///
+/// Every nonzero digit is odd with absolute value below 2^(w-1), and any w
+/// consecutive digits contain at most one nonzero digit.
+#[cfg(feature = "alloc")]
+pub(crate) fn wnaf(scalar: &BigUint, window: usize) -> Vec<i8> {
+    assert!(
+        (2..=8).contains(&window),
+        "wNAF window must be between 2 and 8, got {}",
+        window
+    );
+    let modulus = 1i64 << window;
+    let mask = BigUint::from((modulus - 1) as u64);
+
+    let mut k = scalar.clone();
+    let mut digits = Vec::with_capacity(scalar.bits() as usize + 1);
+    while !k.is_zero() {
+        let digit = if k.bit(0) {
+            let low = (&k & &mask).iter_u64_digits().next().unwrap_or(0) as i64;
+            let d = if low >= modulus / 2 {
+                low - modulus
+            } else {
+                low
+            };
+            if d > 0 {
+                k -= BigUint::from(d as u64);
+            } else {
+                k += BigUint::from((-d) as u64);
+            }
+            d as i8
+        } else {
+            0
         };
-        let res0 = p.mul_u128(0);
-        assert!(res0.is_infinity());
+        digits.push(digit);
+        k >>= 1;
+    }
 
-        let res1 = p.mul_u128(1);
-        assert_eq!(res1.to_affine(), p.to_affine());
+    digits
+}
 
-        let res2 = p.mul_u128(2);
-        assert_eq!(res2.to_affine(), p.add(&p).to_affine());
+/// Equality of the represented points, see `G1::eq_affine`. Jacobian
+/// coordinates are only defined up to (λ²X : λ³Y : λZ), so comparing them
+/// field by field would tell apart equal points.
+impl PartialEq for G1 {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_affine(other)
+    }
+}
 
-        let res3 = p.mul_u128(3);
-        assert_eq!(res3.to_affine(), p.add(&p).add(&p).to_affine());
+impl Eq for G1 {}
+
+/// Equality of the represented points, without branching on the data.
+/// The affine coordinates are compared by cross-multiplying,
+/// X1·Z2² = X2·Z1² and Y1·Z2³ = Y2·Z1³, so no inversion is needed, and
+/// two points at infinity are equal whatever their X and Y. The limb
+/// comparisons go through `Fp::ct_eq`; as with `mul_scalar`, this is an
+/// algorithmic guarantee only.
+#[cfg(feature = "ct")]
+impl subtle::ConstantTimeEq for G1 {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        let z1z1 = self.z * self.z;
+        let z2z2 = other.z * other.z;
+        let x_eq = (self.x * z2z2).ct_eq(&(other.x * z1z1));
+        let y_eq = (self.y * z2z2 * other.z).ct_eq(&(other.y * z1z1 * self.z));
+        let inf1 = self.z.ct_eq(&Fp::zero());
+        let inf2 = other.z.ct_eq(&Fp::zero());
+        (inf1 & inf2) | (!inf1 & !inf2 & x_eq & y_eq)
+    }
+}
+
+/// Selects the Jacobian coordinates with `Fp`'s `ConditionallySelectable`
+#[cfg(feature = "ct")]
+impl subtle::ConditionallySelectable for G1 {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self {
+            x: Fp::conditional_select(&a.x, &b.x, choice),
+            y: Fp::conditional_select(&a.y, &b.y, choice),
+            z: Fp::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// The default is the point at infinity (the group identity), not the
+/// generator, so a point left at its default adds nothing to a sum
+impl Default for G1 {
+    fn default() -> Self {
+        G1::infinity()
+    }
+}
+
+/// Displayed as the affine coordinates "(x, y)", or "infinity"
+impl fmt::Display for G1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinity() {
+            return f.write_str("infinity");
+        }
+        let (x, y) = self.to_affine();
+        write!(f, "({}, {})", x, y)
+    }
+}
+
+impl fmt::LowerHex for G1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinity() {
+            return f.write_str("infinity");
+        }
+        let (x, y) = self.to_affine();
+        f.write_str("(")?;
+        fmt::LowerHex::fmt(&x, f)?;
+        f.write_str(", ")?;
+        fmt::LowerHex::fmt(&y, f)?;
+        f.write_str(")")
+    }
+}
+
+/// Negation: -(X:Y:Z) = (X:-Y:Z)
+impl Neg for G1 {
+    type Output = G1;
+    fn neg(self) -> G1 {
+        if self.is_infinity() {
+            return self;
+        }
+        G1 {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+/// Subtraction: P - Q = P + (-Q)
+impl Sub for G1 {
+    type Output = G1;
+    fn sub(self, rhs: G1) -> G1 {
+        self.add(&-rhs)
+    }
+}
+
+impl<'b> Sub<&'b G1> for &G1 {
+    type Output = G1;
+    fn sub(self, rhs: &'b G1) -> G1 {
+        self.add(&-*rhs)
+    }
+}
+
+/// Point addition, delegating to `G1::add`.
+///
+/// Only the by-reference form is provided: a by-value `Add` impl would
+/// shadow the inherent `add` method for owned receivers.
+///
+/// ```
+/// use bn254::g1::G1;
+///
+/// let p = G1::generator();
+/// let q = p.double();
+/// assert_eq!(&p + &q, p.add(&q));
+/// ```
+impl<'b> Add<&'b G1> for &G1 {
+    type Output = G1;
+    fn add(self, rhs: &'b G1) -> G1 {
+        G1::add(self, rhs)
+    }
+}
+
+impl<'b> AddAssign<&'b G1> for G1 {
+    fn add_assign(&mut self, rhs: &'b G1) {
+        *self = G1::add(self, rhs);
+    }
+}
+
+/// Scalar multiplication, delegating to `G1::mul_scalar`
+///
+/// ```
+/// use bn254::g1::G1;
+/// use num_bigint::BigUint;
+///
+/// let p = G1::generator();
+/// let s = BigUint::from(42u32);
+/// assert_eq!(&p * &s, p.mul_scalar(&s));
+/// ```
+#[cfg(feature = "alloc")]
+impl<'b> Mul<&'b BigUint> for &G1 {
+    type Output = G1;
+    fn mul(self, rhs: &'b BigUint) -> G1 {
+        self.mul_scalar(rhs)
+    }
+}
+
+/// Errors returned when validating a curve point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointError {
+    /// The coordinates do not satisfy the curve equation
+    NotOnCurve,
+    /// The point is not in the prime-order subgroup
+    NotInSubgroup,
+}
+
+impl fmt::Display for PointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            PointError::NotOnCurve => "point is not on the curve",
+            PointError::NotInSubgroup => "point is not in the prime-order subgroup",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointError {}
+
+/// Errors returned when decoding a curve point from bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The coordinates do not satisfy the curve equation
+    NotOnCurve,
+    /// The point is not in the prime-order subgroup
+    NotInSubgroup,
+    /// The flag bits of a compressed encoding are malformed
+    InvalidCompressedFlag,
+    /// The input does not have the length of any supported encoding
+    InvalidLength,
+    /// A coordinate is not below the field modulus
+    InvalidFieldElement,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            DecodeError::NotOnCurve => "point is not on the curve",
+            DecodeError::NotInSubgroup => "point is not in the prime-order subgroup",
+            DecodeError::InvalidCompressedFlag => "invalid compressed point flags",
+            DecodeError::InvalidLength => "invalid encoding length",
+            DecodeError::InvalidFieldElement => "coordinate is not below the field modulus",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+impl From<FpError> for DecodeError {
+    fn from(e: FpError) -> Self {
+        match e {
+            FpError::TooLarge => DecodeError::InvalidFieldElement,
+            FpError::WrongLength => DecodeError::InvalidLength,
+        }
+    }
+}
+
+impl From<PointError> for DecodeError {
+    fn from(e: PointError) -> Self {
+        match e {
+            PointError::NotOnCurve => DecodeError::NotOnCurve,
+            PointError::NotInSubgroup => DecodeError::NotInSubgroup,
+        }
+    }
+}
+
+/// Flag bits stored in the top of the first byte of a compressed point.
+/// p < 2^254, so the two most significant bits of x are always free.
+const COMPRESSED_SIGN_FLAG: u8 = 0x80;
+const COMPRESSED_INFINITY_FLAG: u8 = 0x40;
+
+/// A G1 point in affine coordinates
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1Affine {
+    pub x: Fp,
+    pub y: Fp,
+    pub infinity: bool,
+}
+
+impl G1Affine {
+    pub fn infinity() -> Self {
+        Self {
+            x: Fp::zero(),
+            y: Fp::zero(),
+            infinity: true,
+        }
+    }
+
+    /// Check that the point satisfies y² = x³ + 3
+    pub fn is_on_curve(&self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        self.y * self.y == curve_rhs(&self.x)
+    }
+
+    /// Uncompressed encoding x || y, each coordinate 32 bytes big-endian.
+    /// This is the EIP-196 encoding, with infinity encoded as (0, 0).
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        if !self.infinity {
+            out[..32].copy_from_slice(&self.x.to_bytes_be());
+            out[32..].copy_from_slice(&self.y.to_bytes_be());
+        }
+        out
+    }
+
+    /// Decodes an uncompressed point, see `to_uncompressed`
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<Self, DecodeError> {
+        let x = Fp::from_bytes_be(&bytes[..32])?;
+        let y = Fp::from_bytes_be(&bytes[32..])?;
+        if x == Fp::zero() && y == Fp::zero() {
+            return Ok(Self::infinity());
+        }
+
+        let p = Self {
+            x,
+            y,
+            infinity: false,
+        };
+        if !p.is_on_curve() {
+            return Err(DecodeError::NotOnCurve);
+        }
+        // The cofactor of G1 is 1, so every point on the curve is in the
+        // prime-order subgroup
+        Ok(p)
+    }
+
+    /// Compressed encoding: x as 32 bytes big-endian, with the most
+    /// significant bit set when y is the larger of its two possible values
+    /// and the next bit set for the point at infinity
+    pub fn to_compressed(&self) -> [u8; 32] {
+        if self.infinity {
+            let mut out = [0u8; 32];
+            out[0] = COMPRESSED_INFINITY_FLAG;
+            return out;
+        }
+        let mut out = self.x.to_bytes_be();
+        if is_larger_root(&self.y) {
+            out[0] |= COMPRESSED_SIGN_FLAG;
+        }
+        out
+    }
+
+    /// Decodes a compressed point, recovering y as a square root of
+    /// x³ + 3 and selecting the root indicated by the sign flag
+    pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, DecodeError> {
+        let flags = bytes[0] & (COMPRESSED_SIGN_FLAG | COMPRESSED_INFINITY_FLAG);
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !(COMPRESSED_SIGN_FLAG | COMPRESSED_INFINITY_FLAG);
+
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            if flags != COMPRESSED_INFINITY_FLAG || x_bytes.iter().any(|&b| b != 0) {
+                return Err(DecodeError::InvalidCompressedFlag);
+            }
+            return Ok(Self::infinity());
+        }
+
+        let x = Fp::from_bytes_be(&x_bytes)?;
+        let y = curve_rhs(&x).sqrt().ok_or(DecodeError::NotOnCurve)?;
+        let y = if is_larger_root(&y) == (flags & COMPRESSED_SIGN_FLAG != 0) {
+            y
+        } else {
+            -y
+        };
+
+        Ok(Self {
+            x,
+            y,
+            infinity: false,
+        })
+    }
+
+    /// Decodes either encoding, dispatching on the input length
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        match bytes.len() {
+            32 => Self::from_compressed(bytes.try_into().unwrap()),
+            64 => Self::from_uncompressed(bytes.try_into().unwrap()),
+            _ => Err(DecodeError::InvalidLength),
+        }
+    }
+}
+
+/// The default is the point at infinity, see `G1::default`
+impl Default for G1Affine {
+    fn default() -> Self {
+        Self::infinity()
+    }
+}
+
+/// Orders points at infinity first and the rest lexicographically by
+/// (x, y), see `Ord for Fp`, so affine points can be sorted or used as
+/// `BTreeMap` keys
+impl Ord for G1Affine {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Finite points compare as true, which sorts after false
+        (!self.infinity, &self.x, &self.y).cmp(&(!other.infinity, &other.x, &other.y))
+    }
+}
+
+impl PartialOrd for G1Affine {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<G1> for G1Affine {
+    fn from(p: G1) -> Self {
+        if p.is_infinity() {
+            return Self::infinity();
+        }
+        let (x, y) = p.to_affine();
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+}
+
+/// From the output of `G1::to_affine` or `G1::batch_normalize`, which
+/// give (0, 0) for the point at infinity. (0, 0) is not on the curve, so
+/// it is read back as infinity.
+impl From<(Fp, Fp)> for G1Affine {
+    fn from((x, y): (Fp, Fp)) -> Self {
+        if x.is_zero() && y.is_zero() {
+            return Self::infinity();
+        }
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+}
+
+impl From<G1Affine> for G1 {
+    fn from(p: G1Affine) -> Self {
+        if p.infinity {
+            return Self::infinity();
+        }
+        Self {
+            x: p.x,
+            y: p.y,
+            z: Fp::one(),
+        }
+    }
+}
+
+/// A G1 point in homogeneous projective coordinates (X:Y:Z), representing
+/// the affine point (X/Z, Y/Z), with infinity as (0:1:0).
+///
+/// The formulas are the complete ones of Renes, Costello and Batina,
+/// "Complete addition formulas for prime order elliptic curves" (2015),
+/// algorithms 7-9 for a = 0: they have no special cases for doubling or
+/// the identity. Measured against `G1` with `cargo bench --bench g1`, a
+/// full addition is cheaper here (12M against Jacobian's 11M + 5S plus its
+/// special-case checks) but doubling is on par and mixed addition is
+/// slower (11M against 7M + 4S). Those two dominate scalar multiplication
+/// and the bucket MSM, which came out about 20% and 50% slower than in
+/// Jacobian coordinates, so `G1` stays the primary representation.
+///
+/// Extended twisted Edwards coordinates, the other common alternative, are
+/// not available for this curve: every twisted Edwards curve has the
+/// rational point (0, -1) of order 2, so its group order is even, while
+/// E(Fp) has prime order r. No birational map to an Edwards model over Fp
+/// exists.
+#[derive(Clone, Debug)]
+pub struct G1Projective {
+    pub x: Fp,
+    pub y: Fp,
+    pub z: Fp,
+}
+
+/// 3b = 9, the curve constant appearing in the complete formulas
+fn b3() -> Fp {
+    Fp::from(9u32)
+}
+
+impl G1Projective {
+    pub fn infinity() -> Self {
+        Self {
+            x: Fp::zero(),
+            y: Fp::one(),
+            z: Fp::zero(),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Doubling, algorithm 9 of Renes-Costello-Batina (6M + 2S)
+    pub fn double(&self) -> Self {
+        let t0 = self.y * self.y;
+        let z3 = t0 + t0;
+        let z3 = z3 + z3;
+        let z3 = z3 + z3;
+        let t1 = self.y * self.z;
+        let t2 = b3() * (self.z * self.z);
+        let x3 = t2 * z3;
+        let y3 = t0 + t2;
+        let z3 = t1 * z3;
+        let t2 = t2 + t2 + t2;
+        let t0 = t0 - t2;
+        let y3 = x3 + t0 * y3;
+        let t1 = self.x * self.y;
+        let x3 = t0 * t1;
+        Self {
+            x: x3 + x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Complete addition, algorithm 7 of Renes-Costello-Batina (12M)
+    pub fn add(&self, other: &Self) -> Self {
+        let t0 = self.x * other.x;
+        let t1 = self.y * other.y;
+        let t2 = self.z * other.z;
+        let t3 = (self.x + self.y) * (other.x + other.y) - (t0 + t1);
+        let t4 = (self.y + self.z) * (other.y + other.z) - (t1 + t2);
+        let y3 = (self.x + self.z) * (other.x + other.z) - (t0 + t2);
+        Self::finish_add(t0, t1, t2, t3, t4, y3)
+    }
+
+    /// Mixed addition of an affine point, algorithm 8 of
+    /// Renes-Costello-Batina (11M). The formulas assume Z2 = 1, so the
+    /// affine point at infinity is handled separately.
+    pub fn add_affine(&self, other: &G1Affine) -> Self {
+        if other.infinity {
+            return self.clone();
+        }
+        let t0 = self.x * other.x;
+        let t1 = self.y * other.y;
+        let t3 = (other.x + other.y) * (self.x + self.y) - (t0 + t1);
+        let t4 = other.y * self.z + self.y;
+        let y3 = other.x * self.z + self.x;
+        Self::finish_add(t0, t1, self.z, t3, t4, y3)
+    }
+
+    /// The steps shared by algorithms 7 and 8 once X1X2, Y1Y2, Z1Z2 and the
+    /// three cross terms are known
+    fn finish_add(t0: Fp, t1: Fp, t2: Fp, t3: Fp, t4: Fp, y3: Fp) -> Self {
+        let t0 = t0 + t0 + t0;
+        let t2 = b3() * t2;
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = b3() * y3;
+        let x3 = t3 * t1 - t4 * y3;
+        let y3 = t1 * z3 + y3 * t0;
+        let z3 = z3 * t4 + t0 * t3;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+/// The default is the point at infinity, see `G1::default`
+impl Default for G1Projective {
+    fn default() -> Self {
+        Self::infinity()
+    }
+}
+
+/// Equality of the represented points: X1·Z2 = X2·Z1 and Y1·Z2 = Y2·Z1
+impl PartialEq for G1Projective {
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl Eq for G1Projective {}
+
+impl From<G1> for G1Projective {
+    /// (X:Y:Z) in Jacobian coordinates is (XZ : Y : Z³) in homogeneous ones
+    fn from(p: G1) -> Self {
+        if p.is_infinity() {
+            return Self::infinity();
+        }
+        let z3 = p.z * p.z * p.z;
+        Self {
+            x: p.x * p.z,
+            y: p.y,
+            z: z3,
+        }
+    }
+}
+
+impl From<G1Projective> for G1 {
+    /// (X:Y:Z) in homogeneous coordinates is (XZ : YZ² : Z) in Jacobian ones
+    fn from(p: G1Projective) -> Self {
+        if p.is_infinity() {
+            return Self::infinity();
+        }
+        let zz = p.z * p.z;
+        Self {
+            x: p.x * p.z,
+            y: p.y * zz,
+            z: p.z,
+        }
+    }
+}
+
+impl From<G1Affine> for G1Projective {
+    fn from(p: G1Affine) -> Self {
+        if p.infinity {
+            return Self::infinity();
+        }
+        Self {
+            x: p.x,
+            y: p.y,
+            z: Fp::one(),
+        }
+    }
+}
+
+/// Serde representation of an affine point, shared by G1 and G2
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct AffineRepr<F> {
+    pub(crate) x: F,
+    pub(crate) y: F,
+    pub(crate) infinity: bool,
+}
+
+/// Serialized in affine form as `{"x": .., "y": .., "infinity": ..}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1Affine {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AffineRepr {
+            x: self.x,
+            y: self.y,
+            infinity: self.infinity,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1Affine {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = AffineRepr::<Fp>::deserialize(deserializer)?;
+        if repr.infinity {
+            return Ok(Self::infinity());
+        }
+        let p = Self {
+            x: repr.x,
+            y: repr.y,
+            infinity: false,
+        };
+        if !p.is_on_curve() {
+            return Err(serde::de::Error::custom(DecodeError::NotOnCurve));
+        }
+        Ok(p)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        G1Affine::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        G1Affine::deserialize(deserializer).map(G1::from)
+    }
+}
+
+/// Whether y is the larger of y and -y as integers in [0, p)
+pub(crate) fn is_larger_root(y: &Fp) -> bool {
+    // -y = p - y, so y is the larger one exactly when y > (p - 1) / 2
+    let y = y.to_u64_le_digits();
+    y.iter().rev().gt(Fp::P_MINUS_1_OVER_2.iter().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use rand::Rng;
+
+    #[test]
+    fn test_generator_on_curve() {
+        let g = G1::generator();
+        assert!(g.is_on_curve());
+        assert_eq!(g.to_affine(), (Fp::one(), Fp::new(2u32.into())));
+        assert!(g.double().is_on_curve());
+        assert!(g.mul_u128(5).is_on_curve());
+    }
+
+    #[test]
+    fn test_infinity() {
+        let inf = G1::infinity();
+        assert!(inf.is_infinity());
+        assert_eq!(inf.to_affine(), (Fp::zero(), Fp::zero()));
+    }
+
+    #[test]
+    fn test_affine_conversion() {
+        // (1, 2) scaled by Z = 3
+        let p = G1 {
+            x: Fp::new(9u32.into()),
+            y: Fp::new(54u32.into()),
+            z: Fp::new(3u32.into()),
+        };
+        let (x_aff, y_aff) = p.to_affine();
+        assert_eq!(x_aff, Fp::new(1u32.into()));
+        assert_eq!(y_aff, Fp::new(2u32.into()));
+    }
+
+    #[test]
+    fn test_from_affine() {
+        let (x, y) = G1::generator().mul_u128(9).to_affine();
+        let p = G1::from_affine(x, y).unwrap();
+        assert_eq!(p, G1::generator().mul_u128(9));
+        assert_eq!(p, G1::from_affine_unchecked(x, y));
+        assert_eq!(
+            G1::from_affine(x, Fp::new(6u32.into())),
+            Err(PointError::NotOnCurve)
+        );
+        let off_curve = G1::from_affine_unchecked(Fp::new(3u32.into()), Fp::new(6u32.into()));
+        assert!(!off_curve.is_on_curve());
+    }
+
+    #[test]
+    fn test_on_curve_known_point() {
+        let p = G1::from_affine_unchecked(Fp::new(1u32.into()), Fp::new(2u32.into()));
+        assert!(p.is_on_curve());
+    }
+
+    #[test]
+    fn test_double_vs_add() {
+        let p = G1::generator().mul_u128(3);
+        let double = p.double();
+        let add = p.add(&p);
+        assert_eq!(double.to_affine(), add.to_affine());
+    }
+
+    #[test]
+    fn test_addition_commutative() {
+        let p1 = G1::generator().mul_u128(3);
+        let p2 = G1::generator().mul_u128(5);
+        let sum1 = p1.add(&p2);
+        let sum2 = p2.add(&p1);
+        assert_eq!(sum1.to_affine(), sum2.to_affine());
+    }
+
+    #[test]
+    fn test_negation() {
+        let g = G1::generator();
+        let neg_g = -g;
+        assert!(neg_g.is_on_curve());
+        assert!(g.add(&neg_g).is_infinity());
+        assert!((-G1::infinity()).is_infinity());
+        assert_eq!(-neg_g, g);
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let g = G1::generator();
+        let two_g = g.double();
+        assert!((g - g).is_infinity());
+        assert_eq!((two_g - g).to_affine(), g.to_affine());
+        assert_eq!((two_g - g).to_affine(), g.to_affine());
+        assert_eq!(g - G1::infinity(), g);
+        assert_eq!(G1::infinity() - g, -g);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = G1::generator();
+        assert!(g.mul_scalar(&BigUint::zero()).is_infinity());
+        assert_eq!(g.mul_scalar(&BigUint::from(1u32)), g);
+
+        // Scalars wider than 128 bits
+        let k = BigUint::from(u128::MAX) + BigUint::from(2u32);
+        let expected = g.mul_u128(u128::MAX).add(&g.double());
+        assert_eq!(g.mul_scalar(&k).to_affine(), expected.to_affine());
+
+        // The generator has order r
+        let r = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap();
+        assert!(g.mul_scalar(&r).is_infinity());
+        let r_minus_one = &r - BigUint::from(1u32);
+        assert_eq!(g.mul_scalar(&r_minus_one).to_affine(), (-g).to_affine());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..1000).map(|_| G1::random(&mut rng)).collect();
+        assert!(points.iter().all(|p| p.is_on_curve()));
+
+        let mut seen: Vec<_> = G1::batch_normalize(&points)
+            .into_iter()
+            .map(|(x, y)| (x.to_bytes_be(), y.to_bytes_be()))
+            .collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 1000);
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let g = G1::generator();
+        assert_eq!(g.clear_cofactor(), g);
+        let p = g.mul_u128(12345);
+        assert_eq!(p.clear_cofactor(), p);
+    }
+
+    #[cfg(feature = "ct")]
+    #[test]
+    fn test_ct_eq() {
+        use subtle::ConstantTimeEq;
+        let p = G1::generator().mul_u128(5);
+        // The same point with Z scaled by 7
+        let l = Fp::from(7u32);
+        let q = G1 {
+            x: p.x * l * l,
+            y: p.y * l * l * l,
+            z: p.z * l,
+        };
+        assert_ne!(p.z, q.z);
+        assert_eq!(p, q);
+        assert!(bool::from(p.ct_eq(&q)));
+        assert!(!bool::from(p.ct_eq(&-q)));
+        assert!(!bool::from(p.ct_eq(&G1::infinity())));
+        assert!(!bool::from(G1::infinity().ct_eq(&p)));
+        let inf = G1 {
+            x: Fp::one(),
+            y: Fp::one(),
+            z: Fp::zero(),
+        };
+        assert!(bool::from(inf.ct_eq(&G1::infinity())));
+    }
+
+    #[test]
+    fn test_select() {
+        let a = G1::generator();
+        let b = a.double();
+        assert_eq!(G1::select(&a, &b, false), a);
+        assert_eq!(G1::select(&a, &b, true), b);
+    }
+
+    #[cfg(feature = "ct")]
+    #[test]
+    fn test_conditional_select() {
+        use subtle::{Choice, ConditionallySelectable};
+        let a = G1::generator();
+        let b = a.double();
+        assert_eq!(G1::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(G1::conditional_select(&a, &b, Choice::from(1)), b);
+    }
+
+    #[test]
+    fn test_display() {
+        let g = G1::generator();
+        assert_eq!(g.to_string(), "(1, 2)");
+        assert_eq!(
+            format!("{:#x}", g),
+            concat!(
+                "(0x0000000000000000000000000000000000000000000000000000000000000001, ",
+                "0x0000000000000000000000000000000000000000000000000000000000000002)"
+            )
+        );
+        assert_eq!(G1::infinity().to_string(), "infinity");
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        // Computed with an independent implementation of RFC 9380
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+        let vectors = [
+            (
+                &b""[..],
+                "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+                "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+            ),
+            (
+                &b"abc"[..],
+                "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+                "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let p = hash_to_g1(msg, dst);
+            assert!(p.is_on_curve());
+            let (px, py) = p.to_affine();
+            assert_eq!(
+                (format!("{:x}", px), format!("{:x}", py)),
+                (x.into(), y.into())
+            );
+        }
+
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_";
+        let (px, py) = encode_to_g1(b"", dst).to_affine();
+        assert_eq!(
+            format!("{:x}", px),
+            "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925"
+        );
+        assert_eq!(
+            format!("{:x}", py),
+            "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11"
+        );
+
+        let mut seen = Vec::new();
+        for i in 0u32..10 {
+            let p = hash_to_g1(&i.to_be_bytes(), dst);
+            assert!(p.is_on_curve());
+            assert!(!seen.contains(&p.to_affine()));
+            seen.push(p.to_affine());
+        }
+    }
+
+    #[test]
+    fn test_svdw_exceptional_inputs() {
+        // u = 0 maps to (c2, sqrt(g(c2)))
+        let p = map_to_g1(&Fp::zero());
+        assert!(p.is_on_curve());
+        assert_eq!(p.x, SVDW_C2.clone());
+        // 1 - c1·u² = 0 for u = ±1/2
+        let half = Fp::new(2u32.into()).inv();
+        assert!(map_to_g1(&half).is_on_curve());
+        assert!(map_to_g1(&-half).is_on_curve());
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let u = Fp::from(rng.gen::<u128>()) * Fp::from(rng.gen::<u128>());
+            assert!(map_to_g1(&u).is_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_glv() {
+        let g = G1::generator();
+        let lambda = &*GLV_LAMBDA;
+        assert_eq!(
+            g.endomorphism().to_affine(),
+            g.mul_scalar(lambda).to_affine()
+        );
+
+        let r = &*GROUP_ORDER;
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![
+            BigUint::zero(),
+            BigUint::from(1u32),
+            lambda.clone(),
+            r - BigUint::from(1u32),
+            r.clone(),
+        ];
+        for _ in 0..5 {
+            scalars.push(BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()));
+        }
+        for k in &scalars {
+            let (k1, k2) = glv_decompose(k);
+            assert!(k1.bits() <= 128 && k2.bits() <= 128);
+            let recomposed = (k1 + k2 * BigInt::from(lambda.clone())) - BigInt::from(k.clone());
+            assert!((recomposed % BigInt::from(r.clone())).is_zero());
+
+            let p = g.mul_u128(rng.gen());
+            assert_eq!(p.mul_scalar_glv(k).to_affine(), p.mul_scalar(k).to_affine());
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        let g = G1::generator();
+        let k = BigUint::from(5u32);
+        let mut acc = G1::infinity();
+        for _ in 0..5 {
+            acc += &g;
+        }
+        assert_eq!(acc.to_affine(), (&g * &k).to_affine());
+        assert_eq!(((&g + &g) - g).to_affine(), g.to_affine());
+        assert!((&g + &-g).is_infinity());
+    }
+
+    #[test]
+    fn test_add_affine() {
+        let g = G1::generator();
+        let p = g.mul_u128(7);
+        let q = g.mul_u128(11);
+        let q_aff = G1Affine::from(q);
+        assert_eq!(G1::from(q_aff.clone()), G1::from(G1Affine::from(q)));
+        assert_eq!(p.add_affine(&q_aff).to_affine(), p.add(&q).to_affine());
+        assert_eq!(q.add_affine(&q_aff).to_affine(), q.double().to_affine());
+        assert!((-q).add_affine(&q_aff).is_infinity());
+        assert_eq!(G1::infinity().add_affine(&q_aff).to_affine(), q.to_affine());
+        assert_eq!(p.add_affine(&G1Affine::infinity()), p);
+        assert_eq!(G1Affine::from(G1::infinity()), G1Affine::infinity());
+    }
+
+    #[test]
+    fn test_projective() {
+        let g = G1::generator();
+        let p = g.mul_u128(7);
+        let q = g.mul_u128(11);
+        let (pp, qp) = (p.to_projective(), q.to_projective());
+        let inf = G1Projective::infinity();
+        let jac = |a: G1Projective| G1::from(a).to_affine();
+
+        assert_eq!(jac(pp.clone()), p.to_affine());
+        assert_eq!(jac(pp.double()), p.double().to_affine());
+        assert_eq!(jac(pp.add(&qp)), p.add(&q).to_affine());
+        assert_eq!(jac(pp.add_affine(&affine(&q))), p.add(&q).to_affine());
+
+        // The formulas are complete: doubling through add, inverses and
+        // the identity need no special cases
+        assert_eq!(pp.add(&pp), pp.double());
+        assert!(pp.add(&(-p).to_projective()).is_infinity());
+        assert_eq!(pp.add(&inf), pp);
+        assert_eq!(inf.add(&pp), pp);
+        assert!(inf.double().is_infinity());
+        assert_eq!(inf.add_affine(&affine(&p)), pp);
+        assert_eq!(pp.add_affine(&G1Affine::infinity()), pp);
+        assert!(G1::from(inf).is_infinity());
+        assert!(G1::infinity().to_projective().is_infinity());
+    }
+
+    #[test]
+    fn test_fixed_base() {
+        let mut rng = rand::thread_rng();
+        let p = G1::generator().mul_u128(rng.gen());
+        for w in [1, 4, 5, 8] {
+            let table = p.precompute_fixed_base(w);
+            assert_eq!(table.table.len(), 256usize.div_ceil(w) * ((1 << w) - 1));
+            for k in [
+                BigUint::zero(),
+                BigUint::from(rng.gen::<u128>()),
+                &*GROUP_ORDER - 1u32,
+                &*GROUP_ORDER + 5u32,
+            ] {
+                assert_eq!(table.mul(&k).to_affine(), p.mul_scalar(&k).to_affine());
+            }
+        }
+        let table = G1::infinity().precompute_fixed_base(4);
+        assert!(table.mul(&BigUint::from(7u32)).is_infinity());
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let g = G1::generator();
+        let mut points: Vec<G1> = (1..10u128).map(|k| g.mul_u128(k)).collect();
+        points.insert(4, G1::infinity());
+        let affine = G1::batch_normalize(&points);
+        assert_eq!(affine.len(), points.len());
+        for (p, a) in points.iter().zip(affine.iter()) {
+            assert_eq!(p.to_affine(), *a);
+            // Back through the affine tuple to Jacobian form
+            assert_eq!(G1Affine::from(*a), G1Affine::from(*p));
+            assert_eq!(G1::from(G1Affine::from(*a)), *p);
+        }
+        assert!(G1::batch_normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_mul_gen() {
+        let mut rng = rand::thread_rng();
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            -&Scalar::one(),
+            Scalar::from(rng.gen::<u128>()),
+            &Scalar::from(rng.gen::<u128>()) * &Scalar::from(rng.gen::<u128>()),
+        ];
+        for s in &scalars {
+            let expected = G1::generator().mul_scalar(s.as_biguint());
+            assert_eq!(affine(&G1::mul_gen(s)), affine(&expected));
+        }
+    }
+
+    #[test]
+    fn test_affine_ord() {
+        use alloc::collections::BTreeSet;
+
+        let g = G1::generator();
+        let points: Vec<G1Affine> = [3u128, 1, 2, 3, 1]
+            .iter()
+            .map(|&k| G1Affine::from(g.mul_u128(k)))
+            .chain([G1Affine::infinity(), G1Affine::from(G1::infinity())])
+            .collect();
+        let set: BTreeSet<G1Affine> = points.iter().cloned().collect();
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.first(), Some(&G1Affine::infinity()));
+        assert!(set.contains(&G1Affine::from(g.double())));
+
+        let finite: Vec<_> = set.iter().skip(1).collect();
+        assert!(finite
+            .windows(2)
+            .all(|w| (&w[0].x, &w[0].y) < (&w[1].x, &w[1].y)));
+    }
+
+    #[test]
+    fn test_eq_affine() {
+        // The generator (1, 2) as (4 : 16 : 2), i.e. scaled by λ = 2
+        let g = G1::generator();
+        let scaled = G1 {
+            x: Fp::new(4u32.into()),
+            y: Fp::new(16u32.into()),
+            z: Fp::new(2u32.into()),
+        };
+        assert!(scaled.eq_affine(&g));
+        assert_eq!(scaled, g);
+        assert_eq!(g.double().add(&g), scaled.add(&scaled).add(&g));
+        assert_ne!(scaled, -g);
+        assert_ne!(g, G1::infinity());
+
+        let other_infinity = G1 {
+            x: Fp::one(),
+            y: Fp::new(7u32.into()),
+            z: Fp::zero(),
+        };
+        assert_eq!(other_infinity, G1::infinity());
+    }
+
+    #[test]
+    fn test_add_affine_points() {
+        let mut rng = rand::thread_rng();
+        let p = affine(&G1::generator().mul_u128(rng.gen()));
+        let q = affine(&G1::generator().mul_u128(rng.gen()));
+        let expected = G1::from(p.clone()).add_affine(&q);
+        assert_eq!(affine(&G1::add_affine_points(&p, &q)), affine(&expected));
+
+        let double = G1::add_affine_points(&p, &p);
+        assert_eq!(affine(&double), affine(&G1::from(p.clone()).double()));
+        let neg = affine(&-G1::from(p.clone()));
+        assert!(G1::add_affine_points(&p, &neg).is_infinity());
+        let inf = G1Affine::infinity();
+        assert_eq!(affine(&G1::add_affine_points(&inf, &q)), q);
+        assert_eq!(affine(&G1::add_affine_points(&p, &inf)), p);
+    }
+
+    #[test]
+    fn test_subgroup_check() {
+        let g = G1::generator();
+        assert!(g.is_in_subgroup());
+        assert!(g.is_in_subgroup_fast());
+        assert!(g.is_torsion_free());
+        assert!(G1::infinity().is_in_subgroup());
+        assert_eq!(g.mul_u128(12345).validate(), Ok(()));
+
+        let bad = G1 {
+            x: Fp::one(),
+            y: Fp::one(),
+            z: Fp::one(),
+        };
+        assert_eq!(bad.validate(), Err(PointError::NotOnCurve));
+        assert_eq!(
+            DecodeError::from(PointError::NotInSubgroup),
+            DecodeError::NotInSubgroup
+        );
+    }
+
+    #[test]
+    fn test_wnaf_digits() {
+        let mut rng = rand::thread_rng();
+        for window in 2..=6 {
+            let n = rng.gen::<u128>() >> 1;
+            let k = BigUint::from(n);
+            let digits = wnaf(&k, window);
+            // The compile-time version agrees, up to trailing zeros
+            let fixed: [i8; 129] = crate::wnaf(n, window as u32);
+            assert_eq!(digits[..], fixed[..digits.len()]);
+            assert!(fixed[digits.len()..].iter().all(|&d| d == 0));
+            let mut acc = num_bigint::BigInt::zero();
+            for &d in digits.iter().rev() {
+                acc = acc * 2 + d;
+                assert!(d == 0 || (d % 2 != 0 && (d.unsigned_abs() as usize) < 1 << (window - 1)));
+            }
+            assert_eq!(acc, k.into());
+            for w in digits.windows(window) {
+                assert!(w.iter().filter(|&&d| d != 0).count() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_by_fp() {
+        let g = G1::generator();
+        let expected = g.mul_scalar(&BigUint::from(7u32));
+        assert_eq!(g.mul_by_fp(&Fp::from(7u32)), expected);
+        assert!(g.mul_by_fp(&Fp::zero()).is_infinity());
+
+        // Values in [r, p) wrap around the group order
+        let r_plus_7 = Fp::new(&*GROUP_ORDER + 7u32);
+        assert_eq!(G1::mul_by_fp(&g, &r_plus_7), expected);
+    }
+
+    #[test]
+    fn test_naf_mul() {
+        let mut rng = rand::thread_rng();
+        let p = G1::generator().mul_u128(rng.gen());
+        let mut scalars = vec![
+            BigUint::zero(),
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            &*GROUP_ORDER - 1u32,
+            (BigUint::from(1u32) << 256) - 1u32,
+        ];
+        scalars.extend((0..4).map(|_| {
+            let bytes: [u8; 32] = rng.gen();
+            BigUint::from_bytes_be(&bytes)
+        }));
+        for k in &scalars {
+            let naf = to_naf(k);
+            assert!(naf.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+            assert!(naf.len() <= k.bits() as usize + 1);
+            assert_eq!(p.naf_mul(k), p.mul_scalar(k));
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_wnaf() {
+        let g = G1::generator();
+        let r = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap();
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![
+            BigUint::zero(),
+            BigUint::from(1u32),
+            &r - BigUint::from(1u32),
+        ];
+        for _ in 0..3 {
+            scalars.push(BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()));
+        }
+        for k in &scalars {
+            let expected = g.mul_scalar(k).to_affine();
+            for window in [2, 4, 5] {
+                assert_eq!(g.mul_scalar_wnaf(k, window).to_affine(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_scalar_mul() {
+        let mut rng = rand::thread_rng();
+        for n in [1, 2, 64] {
+            let bases: Vec<G1> = (0..n)
+                .map(|_| G1::generator().mul_u128(rng.gen()))
+                .collect();
+            let scalars: Vec<BigUint> = (0..n)
+                .map(|_| BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()))
+                .collect();
+            assert_eq!(
+                multi_scalar_mul(&bases, &scalars).to_affine(),
+                multi_scalar_mul_naive(&bases, &scalars).to_affine()
+            );
+        }
+        assert!(multi_scalar_mul(&[], &[]).is_infinity());
+    }
+
+    #[test]
+    #[should_panic(expected = "multi_scalar_mul")]
+    fn test_multi_scalar_mul_length_mismatch() {
+        multi_scalar_mul(&[G1::generator()], &[]);
+    }
+
+    fn affine(p: &G1) -> G1Affine {
+        let (x, y) = p.to_affine();
+        G1Affine {
+            x,
+            y,
+            infinity: p.is_infinity(),
+        }
+    }
+
+    #[test]
+    fn test_encoding_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let mut points = vec![G1Affine::infinity(), affine(&G1::generator())];
+        for _ in 0..5 {
+            points.push(affine(&G1::generator().mul_u128(rng.gen())));
+        }
+        for p in &points {
+            assert_eq!(
+                G1Affine::from_uncompressed(&p.to_uncompressed()),
+                Ok(p.clone())
+            );
+            assert_eq!(G1Affine::from_compressed(&p.to_compressed()), Ok(p.clone()));
+            assert_eq!(G1Affine::from_bytes(&p.to_compressed()), Ok(p.clone()));
+            assert_eq!(G1Affine::from_bytes(&p.to_uncompressed()), Ok(p.clone()));
+        }
+        // Both roots of y round-trip through the sign flag
+        let neg = affine(&-G1::generator());
+        assert_eq!(G1Affine::from_compressed(&neg.to_compressed()), Ok(neg));
+    }
+
+    #[test]
+    fn test_ark_encoding() {
+        // ark-bn254 writes the generator as x = 1 little-endian; y = 2 is
+        // the smaller root, so no flag is set
+        let mut expected = [0u8; 32];
+        expected[0] = 1;
+        assert_eq!(G1::generator().to_ark_bytes(), expected);
+        expected[31] = COMPRESSED_SIGN_FLAG;
+        assert_eq!((-G1::generator()).to_ark_bytes(), expected);
+
+        let mut infinity = [0u8; 32];
+        infinity[31] = COMPRESSED_INFINITY_FLAG;
+        assert_eq!(G1::infinity().to_ark_bytes(), infinity);
+
+        let mut rng = rand::thread_rng();
+        for p in [G1::infinity(), G1::generator().mul_u128(rng.gen())] {
+            let decoded = G1::from_ark_bytes(&p.to_ark_bytes()).unwrap();
+            assert_eq!(affine(&decoded), affine(&p));
+        }
+        // The big-endian compressed bytes of the generator read as
+        // x = 2^248, which is not on the curve
+        assert_eq!(
+            G1::from_ark_bytes(&affine(&G1::generator()).to_compressed()),
+            Err(DecodeError::NotOnCurve)
+        );
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_bellman_vectors() {
+        // pairing_ce's G1Uncompressed of [2]G1 (coordinates from
+        // go-ethereum's bn256Add.json) and of the point at infinity, whose
+        // first byte carries only the 0x40 flag
+        let two: [u8; 64] = hex(
+            "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+             15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+        )
+        .try_into()
+        .unwrap();
+        let p = G1::generator().double();
+        assert_eq!(p.to_bellman_bytes(), two);
+        assert_eq!(affine(&G1::from_bellman_bytes(&two).unwrap()), affine(&p));
+
+        let infinity: [u8; 64] = hex(&format!("40{}", "00".repeat(63))).try_into().unwrap();
+        assert_eq!(G1::infinity().to_bellman_bytes(), infinity);
+        assert!(G1::from_bellman_bytes(&infinity).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_bellman_encoding() {
+        // The generator (1, 2) as written by bellman
+        let mut expected = [0u8; 64];
+        expected[31] = 1;
+        expected[63] = 2;
+        assert_eq!(G1::generator().to_bellman_bytes(), expected);
+        let decoded = G1::from_bellman_bytes(&expected).unwrap();
+        assert_eq!(affine(&decoded), affine(&G1::generator()));
+
+        let mut infinity = [0u8; 64];
+        infinity[0] = COMPRESSED_INFINITY_FLAG;
+        assert_eq!(G1::infinity().to_bellman_bytes(), infinity);
+        assert!(G1::from_bellman_bytes(&infinity).unwrap().is_infinity());
+
+        let mut rng = rand::thread_rng();
+        let p = G1::generator().mul_u128(rng.gen());
+        let decoded = G1::from_bellman_bytes(&p.to_bellman_bytes()).unwrap();
+        assert_eq!(affine(&decoded), affine(&p));
+
+        assert_eq!(
+            G1::from_bellman_bytes(&[0u8; 64]),
+            Err(DecodeError::NotOnCurve)
+        );
+        infinity[63] = 1;
+        assert_eq!(
+            G1::from_bellman_bytes(&infinity),
+            Err(DecodeError::InvalidCompressedFlag)
+        );
+        expected[0] = COMPRESSED_SIGN_FLAG;
+        assert_eq!(
+            G1::from_bellman_bytes(&expected),
+            Err(DecodeError::InvalidCompressedFlag)
+        );
+    }
+
+    #[test]
+    fn test_generator_encoding() {
+        // EIP-196 encoding of (1, 2)
+        let mut expected = [0u8; 64];
+        expected[31] = 1;
+        expected[63] = 2;
+        assert_eq!(affine(&G1::generator()).to_uncompressed(), expected);
+        assert_eq!(G1Affine::infinity().to_uncompressed(), [0u8; 64]);
+    }
+
+    #[test]
+    fn test_decoding_errors() {
+        // (1, 3) is not on the curve
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        bytes[63] = 3;
+        assert_eq!(
+            G1Affine::from_uncompressed(&bytes),
+            Err(DecodeError::NotOnCurve)
+        );
+
+        // x = p is out of range
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&crate::fp::P.to_bytes_be());
+        assert_eq!(
+            G1Affine::from_uncompressed(&bytes),
+            Err(DecodeError::InvalidFieldElement)
+        );
+
+        // x³ + 3 is not a square for x = 4
+        let mut bytes = [0u8; 32];
+        bytes[31] = 4;
+        assert_eq!(
+            G1Affine::from_compressed(&bytes),
+            Err(DecodeError::NotOnCurve)
+        );
+
+        // Infinity flag combined with a nonzero x
+        let mut bytes = [0u8; 32];
+        bytes[0] = COMPRESSED_INFINITY_FLAG;
+        bytes[31] = 1;
+        assert_eq!(
+            G1Affine::from_compressed(&bytes),
+            Err(DecodeError::InvalidCompressedFlag)
+        );
+        bytes[31] = 0;
+        bytes[0] |= COMPRESSED_SIGN_FLAG;
+        assert_eq!(
+            G1Affine::from_compressed(&bytes),
+            Err(DecodeError::InvalidCompressedFlag)
+        );
+
+        assert_eq!(
+            G1Affine::from_bytes(&[0u8; 33]),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_scalar_mul_u128() {
+        let p = G1::generator().mul_u128(3);
+        let res0 = p.mul_u128(0);
+        assert!(res0.is_infinity());
+
+        let res1 = p.mul_u128(1);
+        assert_eq!(res1.to_affine(), p.to_affine());
+
+        let res2 = p.mul_u128(2);
+        assert_eq!(res2.to_affine(), p.add(&p).to_affine());
+
+        let res3 = p.mul_u128(3);
+        assert_eq!(res3.to_affine(), p.add(&p).add(&p).to_affine());
+    }
+
+    #[test]
+    fn test_mul_limbs() {
+        assert_eq!(GROUP_ORDER.to_u64_digits(), GROUP_ORDER_LIMBS);
+        let mut rng = rand::thread_rng();
+        let p = G1::generator().mul_u128(7);
+        for _ in 0..5 {
+            let limbs: [u64; 4] = rng.gen();
+            let bytes: Vec<u8> = limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+            let k = BigUint::from_bytes_le(&bytes);
+            assert_eq!(p.mul_limbs(&limbs), p.mul_scalar(&k));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let one = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let two = "0x0000000000000000000000000000000000000000000000000000000000000002";
+        let zero = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        let json = serde_json::to_string(&G1::generator()).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"x":"{}","y":"{}","infinity":false}}"#, one, two)
+        );
+        assert_eq!(serde_json::from_str::<G1>(&json).unwrap(), G1::generator());
+
+        let p = G1::generator().mul_u128(12345);
+        let json = serde_json::to_string(&p).unwrap();
+        let q: G1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(q.to_affine(), p.to_affine());
+
+        let json = serde_json::to_string(&G1::infinity()).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"x":"{}","y":"{}","infinity":true}}"#, zero, zero)
+        );
+        assert!(serde_json::from_str::<G1>(&json).unwrap().is_infinity());
+
+        let bad = format!(r#"{{"x":"{}","y":"{}","infinity":false}}"#, one, one);
+        assert!(serde_json::from_str::<G1Affine>(&bad).is_err());
     }
 }
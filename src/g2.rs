@@ -1,20 +1,114 @@
+use crate::constants::{fp2, PSI_COEFF_X, PSI_COEFF_Y};
 use crate::fp::Fp;
+#[cfg(feature = "alloc")]
+use crate::fp2::batch_inv;
 use crate::fp2::Fp2;
+#[cfg(feature = "serde")]
+use crate::g1::AffineRepr;
+#[cfg(feature = "alloc")]
+use crate::g1::{fixed_base_mul, fixed_base_multiples, wnaf};
+use crate::g1::{is_larger_root as is_larger_fp_root, DecodeError, PointError};
+#[cfg(feature = "alloc")]
+use crate::hash_to_field::hash_to_fp2;
+#[cfg(feature = "alloc")]
+use crate::pairing::G2Precomputed;
+#[cfg(feature = "alloc")]
+use crate::scalar::Scalar;
+#[cfg(feature = "alloc")]
+use crate::GROUP_ORDER;
+use crate::{BN_PARAM, GROUP_ORDER_LIMBS};
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops::Mul;
+use core::ops::{Add, AddAssign, Neg, Sub};
+#[cfg(feature = "alloc")]
 use num_bigint::BigUint;
-use num_traits::Zero;
+
+/// The BN254 G2 generator as given in EIP-197
+const GENERATOR: G2 = G2 {
+    x: fp2(
+        b"10857046999023057135944570762232829481370756359578518086990519993285655852781",
+        b"11559732032986387107991004021392285783925812861821192530917403151452391805634",
+    ),
+    y: fp2(
+        b"8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        b"4082367875863433681332203403145435568316851327593401208105741076214120093531",
+    ),
+    z: Fp2::new(Fp::ONE, Fp::ZERO),
+};
+
+/// The twist coefficient b' = 3/(u+9)
+pub(crate) const TWIST_B: Fp2 = fp2(
+    b"19485874751759354771024239261021720505790618469301721065564631296452457478373",
+    b"266929791119991161246907387137283842545076965332900288569378510910307636690",
+);
+
+/// 6u², the eigenvalue of ψ on G2: p ≡ 6u² (mod r)
+const SIX_U_SQUARED: u128 = 6 * BN_PARAM as u128 * BN_PARAM as u128;
+
+/// Width-5 NAF digits of 6u² and of u for the variable-time
+/// multiplications of the subgroup check and cofactor clearing, see
+/// `crate::wnaf`
+const SIX_U_SQUARED_WNAF: [i8; 129] = crate::wnaf(SIX_U_SQUARED, 5);
+const U_WNAF: [i8; 64] = crate::wnaf(BN_PARAM as u128, 5);
+
+/// Constants of the Shallue-van de Woestijne map (RFC 9380 section 6.6.1)
+/// for the twist with Z = 1, see `g1::hash_to_g1`
+#[cfg(feature = "alloc")]
+const SVDW_C1: Fp2 = fp2(
+    b"19485874751759354771024239261021720505790618469301721065564631296452457478374",
+    b"266929791119991161246907387137283842545076965332900288569378510910307636690",
+);
+#[cfg(feature = "alloc")]
+const SVDW_C2: Fp2 = fp2(
+    b"10944121435919637611123202872628637544348155578648911831344518947322613104291",
+    b"0",
+);
+#[cfg(feature = "alloc")]
+const SVDW_C3: Fp2 = fp2(
+    b"18992192239972082890849143911285057164064277369389217330423471574879236301292",
+    b"21819008332247140148575583693947636719449476128975323941588917397607662637108",
+);
+#[cfg(feature = "alloc")]
+const SVDW_C4: Fp2 = fp2(
+    b"10499238450719652342378357227399831140106360636427411350395554762472100376473",
+    b"6940174569119770192419592065569379906172001098655407502803841283667998553941",
+);
+
+#[cfg(feature = "alloc")]
+lazy_static::lazy_static! {
+    /// Fixed-base table of the generator with 4-bit windows (960 affine
+    /// points), built on first use, see `G2::mul_gen`
+    pub static ref G2_GENERATOR_TABLE: G2FixedBase = G2FixedBase::new(&G2::generator(), 4);
+}
 
 /// G2 is the twisted curve over Fp2
 /// Twist curve equation: y² = x³ + 3/(u + 9)
 /// We use the isomorphic curve: y² = x³ + 3*(u+9)
 /// in Jacobian coordinates (X:Y:Z) where x = X/Z², y = Y/Z³
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// As for `G1`, the coordinate fields are deprecated outside this crate;
+/// build points with `from_affine` or `from_affine_unchecked`.
+#[derive(Clone, Debug)]
 pub struct G2 {
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub x: Fp2,
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub y: Fp2,
+    #[deprecated(note = "build points with `from_affine` or `from_affine_unchecked`")]
     pub z: Fp2,
 }
 
 impl G2 {
+    /// Returns the standard BN254 G2 generator (EIP-197)
+    pub fn generator() -> Self {
+        GENERATOR
+    }
+
     /// Returns the point at infinity
     pub fn infinity() -> Self {
         Self {
@@ -24,9 +118,47 @@ impl G2 {
         }
     }
 
+    /// The affine point (x, y), checked to be on the twist and in the
+    /// prime-order subgroup
+    pub fn from_affine(x: Fp2, y: Fp2) -> Result<Self, PointError> {
+        let q = Self::from_affine_unchecked(x, y);
+        if !q.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+        if !q.is_in_subgroup() {
+            return Err(PointError::NotInSubgroup);
+        }
+        Ok(q)
+    }
+
+    /// The affine point (x, y) without any checks, see
+    /// `G1::from_affine_unchecked`
+    pub fn from_affine_unchecked(x: Fp2, y: Fp2) -> Self {
+        Self {
+            x,
+            y,
+            z: Fp2::one(),
+        }
+    }
+
     /// Check if this point is the point at infinity
     pub fn is_infinity(&self) -> bool {
-        self.z.c0.n.is_zero() && self.z.c1.n.is_zero()
+        self.z.is_zero()
+    }
+
+    /// Whether the two points are equal as affine points, whatever their
+    /// Jacobian scaling: X1·Z2² = X2·Z1² and Y1·Z2³ = Y2·Z1³, which needs
+    /// no inversion. This is what `==` uses.
+    pub fn eq_affine(&self, other: &G2) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => return true,
+            (false, false) => {}
+            _ => return false,
+        }
+        let z1z1 = &self.z * &self.z;
+        let z2z2 = &other.z * &other.z;
+        &self.x * &z2z2 == &other.x * &z1z1
+            && &(&self.y * &z2z2) * &other.z == &(&other.y * &z1z1) * &self.z
     }
 
     /// Convert from Jacobian to affine coordinates
@@ -44,13 +176,27 @@ impl G2 {
 
     /// Get the curve coefficient b' = 3/(u+9)
     /// For the twist, we use b' = 3/(9+u)
-    fn get_b() -> Fp2 {
-        // b' = 3/(9+u)
-        // Compute inverse of (9+u)
-        let nine_plus_u = Fp2::new(Fp::new(9u32.into()), Fp::new(1u32.into()));
-        let inv = nine_plus_u.inv();
-        let three = Fp2::new(Fp::new(3u32.into()), Fp::zero());
-        &three * &inv
+    pub(crate) fn get_b() -> Fp2 {
+        TWIST_B
+    }
+
+    /// Converts many points to affine coordinates with a single Fp2
+    /// inversion, see `G1::batch_normalize`. Points at infinity map to (0, 0).
+    #[cfg(feature = "alloc")]
+    pub fn batch_normalize(points: &[G2]) -> Vec<(Fp2, Fp2)> {
+        let zs: Vec<Fp2> = points.iter().map(|p| p.z.clone()).collect();
+        batch_inv(&zs)
+            .into_iter()
+            .zip(points.iter())
+            .map(|(z_inv, p)| {
+                if p.is_infinity() {
+                    return (Fp2::zero(), Fp2::zero());
+                }
+                let z2 = &z_inv * &z_inv;
+                let z3 = &z2 * &z_inv;
+                (&p.x * &z2, &p.y * &z3)
+            })
+            .collect()
     }
 
     /// Check if the point is on the curve
@@ -166,22 +312,855 @@ impl G2 {
         }
     }
 
-    /// Scalar multiplication using double-and-add
+    /// Mixed addition of an affine point (madd-2007-bl), see `G1::add_affine`
+    pub fn add_affine(&self, other: &G2Affine) -> Self {
+        if other.infinity {
+            return self.clone();
+        }
+        if self.is_infinity() {
+            return other.clone().into();
+        }
+
+        // Z1Z1 = Z1²
+        let z1z1 = &self.z * &self.z;
+        // U2 = X2*Z1Z1
+        let u2 = &other.x * &z1z1;
+        // S2 = Y2*Z1*Z1Z1
+        let s2 = &(&other.y * &self.z) * &z1z1;
+
+        if self.x == u2 {
+            if self.y == s2 {
+                return self.double();
+            } else {
+                return Self::infinity();
+            }
+        }
+
+        // H = U2 - X1
+        let h = &u2 - &self.x;
+        // HH = H², I = 4*HH
+        let hh = &h * &h;
+        let two_hh = &hh + &hh;
+        let i = &two_hh + &two_hh;
+        // J = H*I
+        let j = &h * &i;
+        // r = 2*(S2 - Y1)
+        let r = &(&s2 - &self.y) + &(&s2 - &self.y);
+        // V = X1*I
+        let v = &self.x * &i;
+        // X3 = r² - J - 2*V
+        let two_v = &v + &v;
+        let x3 = &(&(&r * &r) - &j) - &two_v;
+        // Y3 = r*(V - X3) - 2*Y1*J
+        let two_y1_j = &(&self.y * &j) + &(&self.y * &j);
+        let y3 = &(&r * &(&v - &x3)) - &two_y1_j;
+        // Z3 = (Z1+H)² - Z1Z1 - HH
+        let z1_plus_h = &self.z + &h;
+        let z3 = &(&(&z1_plus_h * &z1_plus_h) - &z1z1) - &hh;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Scalar multiplication with a Montgomery ladder, see `G1::mul_scalar`
+    /// for what this does and does not guarantee. When the scalar is
+    /// public, `mul_scalar_endo` and `mul_scalar_wnaf` are faster.
+    #[cfg(feature = "alloc")]
     pub fn mul_scalar(&self, scalar: &BigUint) -> Self {
+        self.ladder(scalar.bits().max(256), |i| scalar.bit(i))
+    }
+
+    /// `mul_scalar` for a scalar given as little-endian 64-bit limbs, see
+    /// `G1::mul_limbs`
+    pub fn mul_limbs(&self, scalar: &[u64; 4]) -> Self {
+        self.ladder(256, |i| (scalar[i as usize / 64] >> (i % 64)) & 1 == 1)
+    }
+
+    /// The Montgomery ladder of `mul_scalar` over the low `bits` bits of
+    /// a scalar
+    fn ladder(&self, bits: u64, bit: impl Fn(u64) -> bool) -> Self {
+        let mut r0 = Self::infinity();
+        let mut r1 = self.clone();
+
+        // Invariant: r1 = r0 + self
+        for i in (0..bits).rev() {
+            let bit = bit(i);
+            let a = Self::select(&r0, &r1, bit);
+            let b = Self::select(&r1, &r0, bit);
+            let sum = a.add(&b);
+            let double = a.double();
+            r0 = Self::select(&double, &sum, bit);
+            r1 = Self::select(&sum, &double, bit);
+        }
+
+        r0
+    }
+
+    /// Returns `b` if `choice` is set and `a` otherwise, see `Fp::select`
+    pub(crate) fn select(a: &Self, b: &Self, choice: bool) -> Self {
+        let select = |x: &Fp2, y: &Fp2| {
+            Fp2::new(
+                Fp::select(&x.c0, &y.c0, choice),
+                Fp::select(&x.c1, &y.c1, choice),
+            )
+        };
+        Self {
+            x: select(&a.x, &b.x),
+            y: select(&a.y, &b.y),
+            z: select(&a.z, &b.z),
+        }
+    }
+
+    /// `mul_scalar_wnaf` with window 5 for digits precomputed by
+    /// `crate::wnaf`, keeping the odd multiples in Jacobian form so that no
+    /// allocation is needed. Only for public scalars such as the curve
+    /// constants.
+    fn mul_wnaf5(&self, digits: &[i8]) -> Self {
+        // table[i] = (2i + 1) * P
+        let double = self.double();
+        let mut table: [G2; 8] = core::array::from_fn(|_| Self::infinity());
+        table[0] = self.clone();
+        for i in 1..table.len() {
+            table[i] = table[i - 1].add(&double);
+        }
+
+        let mut res = Self::infinity();
+        for &d in digits.iter().rev() {
+            res = res.double();
+            if d > 0 {
+                res = res.add(&table[(d as usize - 1) / 2]);
+            } else if d < 0 {
+                res = &res - &table[((-d) as usize - 1) / 2];
+            }
+        }
+        res
+    }
+
+    /// Scalar multiplication using ψ, the G2 analogue of
+    /// `G1::mul_scalar_glv`. On G2, ψ acts as multiplication by λ = 6u²,
+    /// a 127-bit value, so dividing the scalar (reduced mod r) by λ gives
+    /// k = k0 + k1·λ with both halves below 2^127 and no lattice reduction
+    /// is needed. k0·Q + k1·ψ(Q) is then evaluated with a simultaneous
+    /// double-and-add.
+    ///
+    /// The result is only correct for points in G2, where ψ has this
+    /// eigenvalue; use `mul_scalar` for other points on the twist.
+    #[cfg(feature = "alloc")]
+    pub fn mul_scalar_endo(&self, scalar: &BigUint) -> Self {
+        let k = scalar % &*GROUP_ORDER;
+        let lambda = BigUint::from(SIX_U_SQUARED);
+        let (k1, k0) = (&k / &lambda, &k % &lambda);
+        let p0 = self.clone();
+        let p1 = self.psi();
+        let p01 = p0.add(&p1);
+
         let mut res = Self::infinity();
-        let mut base = self.clone();
-        let mut s = scalar.clone();
+        for i in (0..k0.bits().max(k1.bits())).rev() {
+            res = res.double();
+            match (k0.bit(i), k1.bit(i)) {
+                (true, true) => res = res.add(&p01),
+                (true, false) => res = res.add(&p0),
+                (false, true) => res = res.add(&p1),
+                (false, false) => {}
+            }
+        }
 
-        while !s.is_zero() {
-            if s.bit(0) {
-                res = res.add(&base);
+        res
+    }
+
+    /// A uniformly random point of the subgroup, see `G1::random`
+    #[cfg(all(feature = "rand", feature = "alloc"))]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Self::generator().mul_scalar(&crate::g1::random_scalar(rng))
+    }
+
+    /// Scalar multiplication using the width-w NAF of the scalar,
+    /// see `G1::mul_scalar_wnaf`. `window` must be between 2 and 8.
+    ///
+    /// The odd multiples are normalized to affine with one batch inversion,
+    /// so every addition in the main loop is a mixed `add_affine`.
+    #[cfg(feature = "alloc")]
+    pub fn mul_scalar_wnaf(&self, scalar: &BigUint, window: usize) -> Self {
+        let digits = wnaf(scalar, window);
+
+        // table[i] = (2i + 1) * P
+        let double = self.double();
+        let mut table = vec![self.clone()];
+        for i in 1..(1 << (window - 2)) {
+            let next = table[i - 1].add(&double);
+            table.push(next);
+        }
+        let table: Vec<G2Affine> = G2::batch_normalize(&table)
+            .into_iter()
+            .map(G2Affine::from)
+            .collect();
+
+        let mut res = Self::infinity();
+        for &d in digits.iter().rev() {
+            res = res.double();
+            if d > 0 {
+                res = res.add_affine(&table[(d as usize - 1) / 2]);
+            } else if d < 0 {
+                let p = &table[((-d) as usize - 1) / 2];
+                res = res.add_affine(&G2Affine {
+                    x: p.x.clone(),
+                    y: -p.y.clone(),
+                    infinity: p.infinity,
+                });
             }
-            base = base.double();
-            s >>= 1;
         }
 
         res
     }
+
+    /// [k]G for the generator G, using the shared `G2_GENERATOR_TABLE`.
+    /// Variable time, like `G1::mul_gen`.
+    #[cfg(feature = "alloc")]
+    pub fn mul_gen(scalar: &Scalar) -> Self {
+        G2_GENERATOR_TABLE.mul(scalar.as_biguint())
+    }
+
+    /// Precomputes the table of `G2FixedBase` for multiplying this point
+    /// by many scalars, see `G2FixedBase::new`
+    #[cfg(feature = "alloc")]
+    pub fn precompute_fixed_base(&self, window_bits: usize) -> G2FixedBase {
+        G2FixedBase::new(self, window_bits)
+    }
+
+    /// Precomputes the Miller loop line coefficients for this point, for
+    /// use with `pairing::miller_loop_precomputed` when the same Q is
+    /// paired with many G1 points
+    #[cfg(feature = "alloc")]
+    pub fn precompute(&self) -> G2Precomputed {
+        G2Precomputed::new(self)
+    }
+
+    /// The endomorphism ψ = twist⁻¹ ∘ π_p ∘ twist, where π_p is the
+    /// p-power Frobenius on E(Fp12). On Jacobian coordinates this is
+    /// (X:Y:Z) ↦ (conj(X)·ξ^((p-1)/3) : conj(Y)·ξ^((p-1)/2) : conj(Z)).
+    pub fn psi(&self) -> Self {
+        Self {
+            x: &self.x.conjugate() * &PSI_COEFF_X,
+            y: &self.y.conjugate() * &PSI_COEFF_Y,
+            z: self.z.conjugate(),
+        }
+    }
+
+    /// Check membership in the prime-order subgroup.
+    ///
+    /// ψ acts on G2 as multiplication by p ≡ 6u² (mod r), and for BN curves
+    /// the converse holds on the whole twist (Dai, Lin, Zhao, Zhou, "Fast
+    /// subgroup membership testings for G1, G2 and GT on pairing-friendly
+    /// curves", 2022): Q ∈ G2 iff ψ(Q) = [6u²]Q. The 127-bit scalar halves the
+    /// doublings of the naive [r]Q check, at the cost of one ψ (three Fp2
+    /// conjugations and two Fp2 multiplications).
+    pub fn is_in_subgroup(&self) -> bool {
+        let res = (&self.psi() - &self.mul_wnaf5(&SIX_U_SQUARED_WNAF)).is_infinity();
+        debug_assert_eq!(res, self.is_in_subgroup_naive());
+        res
+    }
+
+    /// Subgroup check by computing [r]Q
+    pub fn is_in_subgroup_naive(&self) -> bool {
+        self.mul_limbs(&GROUP_ORDER_LIMBS).is_infinity()
+    }
+
+    /// Whether the point lies in the prime-order subgroup, with no component
+    /// of order dividing the cofactor h2. Same as `is_in_subgroup`, using
+    /// the ψ check.
+    ///
+    /// The twist has h2 > 1, so `is_on_curve` does not imply this; points
+    /// from untrusted sources need both checks (see `G1::is_torsion_free`).
+    pub fn is_torsion_free(&self) -> bool {
+        self.is_in_subgroup()
+    }
+
+    /// Maps a point on the twist into G2 with the Fuentes-Castañeda,
+    /// Knapp and Rodríguez-Henríquez method: [u]Q + ψ([3u]Q) + ψ²([u]Q) + ψ³(Q).
+    /// This is a multiple of [h2]Q by a scalar coprime to r, and costs a
+    /// 63-bit scalar multiplication instead of one by the 254-bit cofactor.
+    pub fn clear_cofactor(&self) -> Self {
+        let uq = self.mul_wnaf5(&U_WNAF);
+        let three_uq = uq.double().add(&uq);
+        let psi2_uq = uq.psi().psi();
+        let psi3_q = self.psi().psi().psi();
+        uq.add(&three_uq.psi()).add(&psi2_uq).add(&psi3_q)
+    }
+
+    /// Compressed encoding used by arkworks' `ark-bn254`: x.c0 || x.c1, each
+    /// 32 bytes little-endian, with the sign and infinity flags in the two
+    /// most significant bits of the last byte. The sign compares c1 first,
+    /// as in `G2Affine::to_compressed`, so this is that encoding
+    /// byte-reversed. EIP-197 instead writes c1 before c0, big-endian.
+    pub fn to_ark_bytes(&self) -> [u8; 64] {
+        let mut out = G2Affine::from(self.clone()).to_compressed();
+        out.reverse();
+        out
+    }
+
+    /// Decodes the arkworks compressed encoding, checking subgroup
+    /// membership, see `to_ark_bytes`
+    pub fn from_ark_bytes(bytes: &[u8; 64]) -> Result<G2, DecodeError> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        G2Affine::from_compressed(&bytes).map(G2::from)
+    }
+
+    /// Uncompressed encoding used by bellman's BN254 backend (`pairing_ce`):
+    /// the EIP-197 layout of `G2Affine::to_uncompressed`, except that
+    /// infinity is all zeros with the infinity flag set in the first byte
+    pub fn to_bellman_bytes(&self) -> [u8; 128] {
+        if self.is_infinity() {
+            let mut out = [0u8; 128];
+            out[0] = COMPRESSED_INFINITY_FLAG;
+            return out;
+        }
+        G2Affine::from(self.clone()).to_uncompressed()
+    }
+
+    /// Decodes the bellman uncompressed encoding, checking the curve
+    /// equation and subgroup membership, see `to_bellman_bytes` and
+    /// `G1::from_bellman_bytes`
+    pub fn from_bellman_bytes(bytes: &[u8; 128]) -> Result<G2, DecodeError> {
+        if bytes[0] & COMPRESSED_SIGN_FLAG != 0 {
+            return Err(DecodeError::InvalidCompressedFlag);
+        }
+        if bytes[0] & COMPRESSED_INFINITY_FLAG != 0 {
+            if bytes[0] != COMPRESSED_INFINITY_FLAG || bytes[1..].iter().any(|&b| b != 0) {
+                return Err(DecodeError::InvalidCompressedFlag);
+            }
+            return Ok(G2::infinity());
+        }
+
+        Ok(G2::from_affine(
+            fp2_from_bytes(bytes[..64].try_into().unwrap())?,
+            fp2_from_bytes(bytes[64..].try_into().unwrap())?,
+        )?)
+    }
+}
+
+/// Right-hand side of the twist equation, x³ + 3/(u+9)
+fn curve_rhs(x: &Fp2) -> Fp2 {
+    &(&(x * x) * x) + &G2::get_b()
+}
+
+/// The Shallue-van de Woestijne map from RFC 9380 section 6.6.1 onto the
+/// twist, see `g1::hash_to_g1`. The output is not yet in G2.
+///
+/// The simplified SWU map through a 3-isogeny, as used for BLS12-381, is
+/// not an option here. The 3-division polynomial of y² = x³ + b is
+/// 3x(x³ + 4b), and −4b is not a cube in Fp2, so the only 3-isogeny defined
+/// over Fp2 has kernel x = 0. Its image y² = x³ − 27b again has a = 0.
+/// RFC 9380 accordingly lists only SvdW suites for BN254.
+#[cfg(feature = "alloc")]
+fn map_to_curve_svdw(u: &Fp2) -> G2 {
+    let tv1 = &(u * u) * &SVDW_C1;
+    let tv2 = &Fp2::one() + &tv1;
+    let tv1 = &Fp2::one() - &tv1;
+    let tv3 = &tv1 * &tv2;
+    let tv3 = if tv3.is_zero() { tv3 } else { tv3.inv() };
+    let tv4 = &(&(u * &tv1) * &tv3) * &SVDW_C3;
+
+    let x1 = &SVDW_C2 - &tv4;
+    let x2 = &SVDW_C2 + &tv4;
+    let x3 = {
+        let t = &(&tv2 * &tv2) * &tv3;
+        &(&(&t * &t) * &SVDW_C4) + &Fp2::one()
+    };
+
+    // At least one of g(x1), g(x2), g(x3) is a square
+    let x = [x1, x2, x3]
+        .into_iter()
+        .find(|x| curve_rhs(x).is_quadratic_residue())
+        .unwrap();
+    let y = curve_rhs(&x).sqrt().unwrap();
+    let y = if u.sgn0() == y.sgn0() { y } else { -y };
+
+    G2 {
+        x,
+        y,
+        z: Fp2::one(),
+    }
+}
+
+/// Hashes a message to G2 following the random-oracle construction of
+/// RFC 9380 with expand_message_xmd over SHA-256, the Shallue-van de
+/// Woestijne map and `G2::clear_cofactor` (BN254G2_XMD:SHA-256_SVDW_RO_).
+/// `dst` is the domain separation tag.
+#[cfg(feature = "alloc")]
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2 {
+    let u = hash_to_fp2(msg, dst, 2);
+    map_to_curve_svdw(&u[0])
+        .add(&map_to_curve_svdw(&u[1]))
+        .clear_cofactor()
+}
+
+/// ψ on an affine point: untwist into E(Fp12), apply the p-power Frobenius
+/// and twist back. The Frobenius of Fp2 is conjugation, and moving it past
+/// the twist scales x by ξ^((p-1)/3) and y by ξ^((p-1)/2); since conjugation
+/// is a field automorphism it does not matter whether the scaling comes
+/// before (by the conjugated constants) or after, as done here.
+pub fn untwist_frobenius_twist(q: &G2Affine) -> G2Affine {
+    if q.infinity {
+        return G2Affine::infinity();
+    }
+    G2Affine {
+        x: &q.x.conjugate() * &PSI_COEFF_X,
+        y: &q.y.conjugate() * &PSI_COEFF_Y,
+        infinity: false,
+    }
+}
+
+/// Equality of the represented points, see `G2::eq_affine`. Jacobian
+/// coordinates are only defined up to (λ²X : λ³Y : λZ), so comparing them
+/// field by field would tell apart equal points.
+impl PartialEq for G2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_affine(other)
+    }
+}
+
+impl Eq for G2 {}
+
+/// The default is the point at infinity (the group identity), not the
+/// generator, see `G1::default`
+impl Default for G2 {
+    fn default() -> Self {
+        G2::infinity()
+    }
+}
+
+/// Displayed as the affine coordinates "(x, y)", or "infinity"
+impl fmt::Display for G2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinity() {
+            return f.write_str("infinity");
+        }
+        let (x, y) = self.to_affine();
+        write!(f, "({}, {})", x, y)
+    }
+}
+
+impl fmt::LowerHex for G2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinity() {
+            return f.write_str("infinity");
+        }
+        let (x, y) = self.to_affine();
+        f.write_str("(")?;
+        fmt::LowerHex::fmt(&x, f)?;
+        f.write_str(", ")?;
+        fmt::LowerHex::fmt(&y, f)?;
+        f.write_str(")")
+    }
+}
+
+/// Negation: -(X:Y:Z) = (X:-Y:Z)
+impl Neg for G2 {
+    type Output = G2;
+    fn neg(self) -> G2 {
+        if self.is_infinity() {
+            return self;
+        }
+        G2 {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+/// Subtraction: P - Q = P + (-Q)
+impl Sub for G2 {
+    type Output = G2;
+    fn sub(self, rhs: G2) -> G2 {
+        self.add(&-rhs)
+    }
+}
+
+impl<'b> Sub<&'b G2> for &G2 {
+    type Output = G2;
+    fn sub(self, rhs: &'b G2) -> G2 {
+        self.add(&-rhs.clone())
+    }
+}
+
+/// Point addition, delegating to `G2::add`.
+///
+/// Only the by-reference form is provided: a by-value `Add` impl would
+/// shadow the inherent `add` method for owned receivers.
+///
+/// ```
+/// use bn254::g2::G2;
+///
+/// let p = G2::generator();
+/// let q = p.double();
+/// assert_eq!(&p + &q, p.add(&q));
+/// ```
+impl<'b> Add<&'b G2> for &G2 {
+    type Output = G2;
+    fn add(self, rhs: &'b G2) -> G2 {
+        G2::add(self, rhs)
+    }
+}
+
+impl<'b> AddAssign<&'b G2> for G2 {
+    fn add_assign(&mut self, rhs: &'b G2) {
+        *self = G2::add(self, rhs);
+    }
+}
+
+/// Scalar multiplication, delegating to `G2::mul_scalar`
+///
+/// ```
+/// use bn254::g2::G2;
+/// use num_bigint::BigUint;
+///
+/// let p = G2::generator();
+/// let s = BigUint::from(42u32);
+/// assert_eq!(&p * &s, p.mul_scalar(&s));
+/// ```
+#[cfg(feature = "alloc")]
+impl<'b> Mul<&'b BigUint> for &G2 {
+    type Output = G2;
+    fn mul(self, rhs: &'b BigUint) -> G2 {
+        self.mul_scalar(rhs)
+    }
+}
+
+/// Multiples of a fixed base point for scalar multiplication without
+/// doublings, laid out as in `G1FixedBase`: ⌈256/w⌉ windows of 2^w - 1
+/// affine points, so 960 points (about 120 KB of Fp2 coordinates) for w = 4
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2FixedBase {
+    pub table: Vec<G2Affine>,
+    pub window_bits: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl G2FixedBase {
+    /// Precomputes the multiples of `base`. Panics unless `window_bits` is
+    /// between 1 and 16.
+    pub fn new(base: &G2, window_bits: usize) -> Self {
+        let points = fixed_base_multiples(base, window_bits, G2::add);
+        let table = G2::batch_normalize(&points)
+            .into_iter()
+            .map(G2Affine::from)
+            .collect();
+        G2FixedBase { table, window_bits }
+    }
+
+    /// [k]P for the precomputed base P. Variable time: the additions
+    /// performed depend on which windows of the scalar are zero.
+    pub fn mul(&self, scalar: &BigUint) -> G2 {
+        fixed_base_mul(
+            &self.table,
+            self.window_bits,
+            scalar,
+            G2::infinity(),
+            G2::add_affine,
+        )
+    }
+}
+
+/// Flag bits stored in the top of the first byte of a compressed point
+const COMPRESSED_SIGN_FLAG: u8 = 0x80;
+const COMPRESSED_INFINITY_FLAG: u8 = 0x40;
+
+/// A G2 point in affine coordinates
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fp2,
+    pub y: Fp2,
+    pub infinity: bool,
+}
+
+impl G2Affine {
+    pub fn infinity() -> Self {
+        Self {
+            x: Fp2::zero(),
+            y: Fp2::zero(),
+            infinity: true,
+        }
+    }
+
+    /// Check that the point satisfies y² = x³ + 3/(u+9)
+    pub fn is_on_curve(&self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        let y2 = &self.y * &self.y;
+        let x3 = &(&self.x * &self.x) * &self.x;
+        y2 == &x3 + &G2::get_b()
+    }
+
+    /// Check membership in the prime-order subgroup, see `G2::is_in_subgroup`
+    pub fn is_in_subgroup(&self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        let q = G2 {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: Fp2::one(),
+        };
+        q.is_in_subgroup()
+    }
+
+    /// π^power(Q): the p-power Frobenius of E(Fp12) applied `power` times,
+    /// read back on the twist (ψ^power, see `G2::psi`). On G2 this is
+    /// multiplication by p^power.
+    pub fn pi_map(&self, power: usize) -> Self {
+        let mut q = self.clone();
+        if q.infinity {
+            return q;
+        }
+        for _ in 0..power {
+            q = untwist_frobenius_twist(&q);
+        }
+        q
+    }
+
+    /// Uncompressed encoding following EIP-197: each Fp2 coordinate is
+    /// written as c1 || c0 (imaginary part first), each 32 bytes big-endian.
+    /// Infinity is encoded as all zeros.
+    pub fn to_uncompressed(&self) -> [u8; 128] {
+        let mut out = [0u8; 128];
+        if !self.infinity {
+            out[..64].copy_from_slice(&fp2_to_bytes(&self.x));
+            out[64..].copy_from_slice(&fp2_to_bytes(&self.y));
+        }
+        out
+    }
+
+    /// Decodes an uncompressed point, checking the curve equation and
+    /// subgroup membership
+    pub fn from_uncompressed(bytes: &[u8; 128]) -> Result<Self, DecodeError> {
+        let x = fp2_from_bytes(bytes[..64].try_into().unwrap())?;
+        let y = fp2_from_bytes(bytes[64..].try_into().unwrap())?;
+        if x == Fp2::zero() && y == Fp2::zero() {
+            return Ok(Self::infinity());
+        }
+
+        let q = Self {
+            x,
+            y,
+            infinity: false,
+        };
+        if !q.is_on_curve() {
+            return Err(DecodeError::NotOnCurve);
+        }
+        if !q.is_in_subgroup() {
+            return Err(DecodeError::NotInSubgroup);
+        }
+        Ok(q)
+    }
+
+    /// Compressed encoding: x as in `to_uncompressed`, with the most
+    /// significant bit set when y is the lexicographically larger of its
+    /// two possible values and the next bit set for the point at infinity
+    pub fn to_compressed(&self) -> [u8; 64] {
+        if self.infinity {
+            let mut out = [0u8; 64];
+            out[0] = COMPRESSED_INFINITY_FLAG;
+            return out;
+        }
+        let mut out = fp2_to_bytes(&self.x);
+        if is_larger_root(&self.y) {
+            out[0] |= COMPRESSED_SIGN_FLAG;
+        }
+        out
+    }
+
+    /// Decodes a compressed point, recovering y as a square root of
+    /// x³ + b and checking subgroup membership
+    pub fn from_compressed(bytes: &[u8; 64]) -> Result<Self, DecodeError> {
+        let flags = bytes[0] & (COMPRESSED_SIGN_FLAG | COMPRESSED_INFINITY_FLAG);
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !(COMPRESSED_SIGN_FLAG | COMPRESSED_INFINITY_FLAG);
+
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            if flags != COMPRESSED_INFINITY_FLAG || x_bytes.iter().any(|&b| b != 0) {
+                return Err(DecodeError::InvalidCompressedFlag);
+            }
+            return Ok(Self::infinity());
+        }
+
+        let x = fp2_from_bytes(&x_bytes)?;
+        let y = curve_rhs(&x).sqrt().ok_or(DecodeError::NotOnCurve)?;
+        let y = if is_larger_root(&y) == (flags & COMPRESSED_SIGN_FLAG != 0) {
+            y
+        } else {
+            -y
+        };
+
+        let q = Self {
+            x,
+            y,
+            infinity: false,
+        };
+        if !q.is_in_subgroup() {
+            return Err(DecodeError::NotInSubgroup);
+        }
+        Ok(q)
+    }
+
+    /// Decodes either encoding, dispatching on the input length
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        match bytes.len() {
+            64 => Self::from_compressed(bytes.try_into().unwrap()),
+            128 => Self::from_uncompressed(bytes.try_into().unwrap()),
+            _ => Err(DecodeError::InvalidLength),
+        }
+    }
+}
+
+/// The default is the point at infinity, see `G1::default`
+impl Default for G2Affine {
+    fn default() -> Self {
+        Self::infinity()
+    }
+}
+
+/// Orders points at infinity first and the rest lexicographically by
+/// (x.c0, x.c1, y.c0, y.c1), see `G1Affine`'s `Ord`
+impl Ord for G2Affine {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Finite points compare as true, which sorts after false
+        (!self.infinity)
+            .cmp(&!other.infinity)
+            .then_with(|| self.x.c0.cmp(&other.x.c0))
+            .then_with(|| self.x.c1.cmp(&other.x.c1))
+            .then_with(|| self.y.c0.cmp(&other.y.c0))
+            .then_with(|| self.y.c1.cmp(&other.y.c1))
+    }
+}
+
+impl PartialOrd for G2Affine {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<G2> for G2Affine {
+    fn from(q: G2) -> Self {
+        if q.is_infinity() {
+            return Self::infinity();
+        }
+        let (x, y) = q.to_affine();
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+}
+
+/// From the output of `G2::to_affine` or `G2::batch_normalize`, which
+/// give (0, 0) for the point at infinity. (0, 0) is not on the curve, so
+/// it is read back as infinity.
+impl From<(Fp2, Fp2)> for G2Affine {
+    fn from((x, y): (Fp2, Fp2)) -> Self {
+        if x.is_zero() && y.is_zero() {
+            return Self::infinity();
+        }
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+}
+
+impl From<G2Affine> for G2 {
+    fn from(q: G2Affine) -> Self {
+        if q.infinity {
+            return Self::infinity();
+        }
+        Self {
+            x: q.x,
+            y: q.y,
+            z: Fp2::one(),
+        }
+    }
+}
+
+/// Serialized in affine form like G1, with Fp2 coordinates as
+/// `{"c0": .., "c1": ..}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2Affine {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AffineRepr {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            infinity: self.infinity,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2Affine {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = AffineRepr::<Fp2>::deserialize(deserializer)?;
+        if repr.infinity {
+            return Ok(Self::infinity());
+        }
+        let q = Self {
+            x: repr.x,
+            y: repr.y,
+            infinity: false,
+        };
+        if !q.is_on_curve() {
+            return Err(serde::de::Error::custom(DecodeError::NotOnCurve));
+        }
+        if !q.is_in_subgroup() {
+            return Err(serde::de::Error::custom(DecodeError::NotInSubgroup));
+        }
+        Ok(q)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        G2Affine::from(self.clone()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        G2Affine::deserialize(deserializer).map(G2::from)
+    }
+}
+
+/// EIP-197 encoding of an Fp2 element: c1 || c0
+fn fp2_to_bytes(a: &Fp2) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(&a.c1.to_bytes_be());
+    out[32..].copy_from_slice(&a.c0.to_bytes_be());
+    out
+}
+
+fn fp2_from_bytes(bytes: &[u8; 64]) -> Result<Fp2, DecodeError> {
+    let c1 = Fp::from_bytes_be(&bytes[..32])?;
+    let c0 = Fp::from_bytes_be(&bytes[32..])?;
+    Ok(Fp2::new(c0, c1))
+}
+
+/// Whether y is the larger of y and -y, comparing c1 first and then c0
+fn is_larger_root(y: &Fp2) -> bool {
+    // c1 and -c1 differ unless both are zero, and then c0 decides
+    if y.c1.is_zero() {
+        is_larger_fp_root(&y.c0)
+    } else {
+        is_larger_fp_root(&y.c1)
+    }
 }
 
 #[cfg(test)]
@@ -189,6 +1168,41 @@ mod tests {
     use super::*;
     use num_bigint::ToBigUint;
     use num_traits::{One, Zero};
+    use rand::Rng;
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..100).map(|_| G2::random(&mut rng)).collect();
+        assert!(points.iter().all(|q| q.is_on_curve() && q.is_in_subgroup()));
+
+        let mut seen: Vec<_> = G2::batch_normalize(&points)
+            .into_iter()
+            .map(|(x, _)| (x.c0.to_bytes_be(), x.c1.to_bytes_be()))
+            .collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 100);
+    }
+
+    #[test]
+    fn test_generator_on_curve() {
+        let g = G2::generator();
+        assert!(g.is_on_curve());
+        assert!(g.double().is_on_curve());
+        assert!(g.add(&g.double()).is_on_curve());
+    }
+
+    #[test]
+    fn test_constants() {
+        let xi = Fp2::new(Fp::from(9u32), Fp::one());
+        assert_eq!(&TWIST_B * &xi, Fp2::new(Fp::from(3u32), Fp::zero()));
+        assert_eq!(
+            BigUint::from(SIX_U_SQUARED).to_string(),
+            "147946756881789318990833708069417712966"
+        );
+    }
 
     #[test]
     fn test_infinity() {
@@ -198,45 +1212,500 @@ mod tests {
 
     #[test]
     fn test_double_vs_add() {
-        // Create a point (we'll use a simple construction)
-        let x = Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into()));
-        let y = Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into()));
-        let p = G2 {
-            x,
-            y,
-            z: Fp2::one(),
-        };
+        let p = G2::generator().mul_scalar(&BigUint::from(3u32));
 
         let doubled = p.double();
         let added = p.add(&p);
-        assert_eq!(doubled.to_affine(), added.to_affine());
+        assert_eq!(doubled, added);
     }
 
     #[test]
-    fn test_addition_commutative() {
-        let p1 = G2 {
-            x: Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
-            y: Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
-            z: Fp2::one(),
+    fn test_affine_ord() {
+        use alloc::collections::BTreeSet;
+
+        let g = G2::generator();
+        let points = [g.clone(), g.double(), g.clone(), G2::infinity(), -g];
+        let set: BTreeSet<G2Affine> = points.iter().map(affine).collect();
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.first(), Some(&G2Affine::infinity()));
+
+        // -G shares x with G and differs in y
+        let finite: Vec<_> = set.iter().skip(1).collect();
+        for w in finite.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            assert!((&a.x.c0, &a.x.c1, &a.y.c0, &a.y.c1) < (&b.x.c0, &b.x.c1, &b.y.c0, &b.y.c1));
+        }
+    }
+
+    #[test]
+    fn test_eq_affine() {
+        let g = G2::generator().double();
+        // (λ²X : λ³Y : λZ) represents the same point for any λ ≠ 0
+        let l = Fp2::new(Fp::new(2u32.into()), Fp::new(5u32.into()));
+        let l2 = &l * &l;
+        let scaled = G2 {
+            x: &g.x * &l2,
+            y: &(&g.y * &l2) * &l,
+            z: &g.z * &l,
         };
-        let p2 = G2 {
-            x: Fp2::new(Fp::new(5u32.into()), Fp::new(6u32.into())),
-            y: Fp2::new(Fp::new(7u32.into()), Fp::new(8u32.into())),
-            z: Fp2::one(),
+        assert_ne!(scaled.x, g.x);
+        assert!(scaled.eq_affine(&g));
+        assert_eq!(scaled, g);
+        assert_ne!(scaled, -g.clone());
+        assert_ne!(g, G2::infinity());
+
+        let other_infinity = G2 {
+            x: Fp2::one(),
+            y: l,
+            z: Fp2::zero(),
         };
+        assert_eq!(other_infinity, G2::infinity());
+    }
+
+    #[test]
+    fn test_addition_commutative() {
+        let p1 = G2::generator().mul_scalar(&BigUint::from(3u32));
+        let p2 = G2::generator().mul_scalar(&BigUint::from(5u32));
 
         let sum1 = p1.add(&p2);
         let sum2 = p2.add(&p1);
         assert_eq!(sum1.to_affine(), sum2.to_affine());
     }
 
+    /// A random multiple of the generator with a random Z coordinate
+    fn random_point(rng: &mut impl rand::Rng) -> G2 {
+        let p = G2::generator().mul_scalar(&rng.gen::<u64>().into());
+        let l = Fp2::new(
+            Fp::new(rng.gen::<u128>().into()),
+            Fp::new(rng.gen::<u128>().into()),
+        );
+        let l2 = &l * &l;
+        let l3 = &l2 * &l;
+        G2 {
+            x: &p.x * &l2,
+            y: &p.y * &l3,
+            z: &p.z * &l,
+        }
+    }
+
     #[test]
-    fn test_scalar_mul() {
-        let p = G2 {
-            x: Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
-            y: Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
+    fn test_negation() {
+        let g = G2::generator();
+        let neg_g = -g.clone();
+        assert!(neg_g.is_on_curve());
+        assert!(g.add(&neg_g).is_infinity());
+        assert!((-G2::infinity()).is_infinity());
+        assert_eq!(-neg_g, g);
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let p = random_point(&mut rng);
+            let q = random_point(&mut rng);
+            assert!(p.is_on_curve());
+            assert!((&p - &p).is_infinity());
+            assert_eq!((&p - &q).to_affine(), p.add(&-q.clone()).to_affine());
+            assert_eq!((p.clone() - q.clone()).to_affine(), (&p - &q).to_affine());
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_wnaf() {
+        let g = G2::generator();
+        let r = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap();
+        let mut rng = rand::thread_rng();
+        let scalars = vec![
+            BigUint::zero(),
+            BigUint::one(),
+            &r - BigUint::one(),
+            BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()),
+        ];
+        for k in &scalars {
+            let expected = g.mul_scalar(k).to_affine();
+            for window in [2, 5] {
+                assert_eq!(g.mul_scalar_wnaf(k, window).to_affine(), expected);
+            }
+        }
+    }
+
+    fn affine(q: &G2) -> G2Affine {
+        let (x, y) = q.to_affine();
+        G2Affine {
+            x,
+            y,
+            infinity: q.is_infinity(),
+        }
+    }
+
+    /// A point on the twist curve outside the prime-order subgroup
+    fn non_subgroup_point() -> G2Affine {
+        let mut x = Fp2::one();
+        loop {
+            let rhs = &(&(&x * &x) * &x) + &G2::get_b();
+            if let Some(y) = rhs.sqrt() {
+                return G2Affine {
+                    x,
+                    y,
+                    infinity: false,
+                };
+            }
+            x = &x + &Fp2::one();
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            G2::generator().to_string(),
+            concat!(
+                "(10857046999023057135944570762232829481370756359578518086990519993285655852781 + ",
+                "11559732032986387107991004021392285783925812861821192530917403151452391805634*u, ",
+                "8495653923123431417604973247489272438418190587263600148770280649306958101930 + ",
+                "4082367875863433681332203403145435568316851327593401208105741076214120093531*u)"
+            )
+        );
+        assert_eq!(format!("{:x}", G2::infinity()), "infinity");
+    }
+
+    #[test]
+    fn test_operators() {
+        let g = G2::generator();
+        let k = BigUint::from(5u32);
+        let mut acc = G2::infinity();
+        for _ in 0..5 {
+            acc += &g;
+        }
+        assert_eq!(acc.to_affine(), (&g * &k).to_affine());
+        assert_eq!((&(&g + &g) - &g).to_affine(), g.to_affine());
+        assert!((&g + &-g.clone()).is_infinity());
+    }
+
+    #[test]
+    fn test_add_affine() {
+        let mut rng = rand::thread_rng();
+        let p = random_point(&mut rng);
+        let q = random_point(&mut rng);
+        let q_aff = G2Affine::from(q.clone());
+        assert_eq!(p.add_affine(&q_aff).to_affine(), p.add(&q).to_affine());
+        assert_eq!(q.add_affine(&q_aff).to_affine(), q.double().to_affine());
+        assert!((-q.clone()).add_affine(&q_aff).is_infinity());
+        assert_eq!(G2::infinity().add_affine(&q_aff).to_affine(), q.to_affine());
+        assert_eq!(p.add_affine(&G2Affine::infinity()), p);
+        assert!(G2::from(G2Affine::infinity()).is_infinity());
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<G2> = (0..5).map(|_| random_point(&mut rng)).collect();
+        points.insert(2, G2::infinity());
+        let affine = G2::batch_normalize(&points);
+        for (p, a) in points.iter().zip(affine.iter()) {
+            assert_eq!(p.to_affine(), *a);
+            // Back through the affine tuple to Jacobian form
+            assert_eq!(G2Affine::from(a.clone()), G2Affine::from(p.clone()));
+            assert_eq!(G2::from(G2Affine::from(a.clone())), *p);
+        }
+    }
+
+    #[test]
+    fn test_subgroup_check() {
+        let mut rng = rand::thread_rng();
+        let g = G2::generator();
+        assert!(g.psi().is_on_curve());
+        assert_eq!(
+            g.psi().to_affine(),
+            g.mul_scalar(&BigUint::from(SIX_U_SQUARED)).to_affine()
+        );
+        assert!(G2::infinity().is_in_subgroup());
+        assert!(random_point(&mut rng).is_in_subgroup());
+
+        let q = non_subgroup_point();
+        let q = G2 {
+            x: q.x,
+            y: q.y,
             z: Fp2::one(),
         };
+        assert!(q.psi().is_on_curve());
+        assert!(q.is_on_curve());
+        assert!(!q.is_in_subgroup());
+        assert!(!q.is_in_subgroup_naive());
+        assert!(!q.is_torsion_free());
+        assert!(g.is_torsion_free());
+    }
+
+    #[test]
+    fn test_subgroup_check_before_cofactor_clearing() {
+        // hash_to_g2 without the cofactor clearing: points on the twist
+        // that are almost never in G2, since the cofactor is about 2^254
+        let dst = b"BN254G2-SUBGROUP-TEST";
+        for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let u = hash_to_fp2(msg, dst, 2);
+            let q = map_to_curve_svdw(&u[0]).add(&map_to_curve_svdw(&u[1]));
+            assert!(q.is_on_curve());
+            assert_ne!(q.psi(), q.mul_scalar(&BigUint::from(SIX_U_SQUARED)));
+            assert!(!q.is_in_subgroup());
+            assert!(!q.is_in_subgroup_naive());
+            assert!(q.clear_cofactor().is_in_subgroup());
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g2() {
+        // Computed with an independent implementation of RFC 9380
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+        let q = hash_to_g2(b"", dst);
+        assert_eq!(
+            format!("{:x}", q),
+            concat!(
+                "(1192005a0f121921a6d5629946199e4b27ff8ee4d6dd4f9581dc550ade851300 + ",
+                "1747d950a6f23c16156e2171bce95d1189b04148ad12628869ed21c96a8c9335*u, ",
+                "0498f6bb5ac309a07d9a8b88e6ff4b8de0d5f27a075830e1eb0e68ea318201d8 + ",
+                "2c9755350ca363ef2cf541005437221c5740086c2e909b71d075152484e845f4*u)"
+            )
+        );
+
+        let mut seen = Vec::new();
+        for i in 0u32..5 {
+            let q = hash_to_g2(&i.to_be_bytes(), dst);
+            assert!(q.is_on_curve());
+            assert!(q.is_in_subgroup_naive());
+            assert!(!seen.contains(&q.to_affine()));
+            seen.push(q.to_affine());
+        }
+    }
+
+    #[test]
+    fn test_mul_scalar_endo() {
+        let mut rng = rand::thread_rng();
+        let q = G2::generator().mul_scalar(&rng.gen::<u64>().into());
+        let mut scalars = vec![
+            BigUint::zero(),
+            BigUint::one(),
+            BigUint::from(SIX_U_SQUARED),
+            &*GROUP_ORDER - 1u32,
+            &*GROUP_ORDER + 3u32,
+        ];
+        for _ in 0..5 {
+            let bytes: [u8; 32] = rng.gen();
+            scalars.push(BigUint::from_bytes_be(&bytes) % &*GROUP_ORDER);
+        }
+        for k in &scalars {
+            assert_eq!(
+                q.mul_scalar_endo(k).to_affine(),
+                q.mul_scalar(&(k % &*GROUP_ORDER)).to_affine()
+            );
+        }
+        assert!(G2::infinity().mul_scalar_endo(&scalars[5]).is_infinity());
+    }
+
+    #[test]
+    fn test_pi_map() {
+        let q = G2::generator().mul_scalar(&BigUint::from(7u32));
+        let qa = G2Affine::from(q.clone());
+        assert_eq!(qa.pi_map(0), qa);
+        assert_eq!(qa.pi_map(1), G2Affine::from(q.psi()));
+        assert_eq!(qa.pi_map(2), G2Affine::from(q.psi().psi()));
+
+        // On G2, π acts as multiplication by p
+        let p_mod_r = &*crate::fp::P % &*GROUP_ORDER;
+        assert_eq!(qa.pi_map(1), G2Affine::from(q.mul_scalar(&p_mod_r)));
+        assert_eq!(G2Affine::infinity().pi_map(1), G2Affine::infinity());
+        assert_eq!(untwist_frobenius_twist(&qa), G2Affine::from(q.psi()));
+        assert_eq!(
+            untwist_frobenius_twist(&G2Affine::infinity()),
+            G2Affine::infinity()
+        );
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let q = non_subgroup_point();
+        let q = G2 {
+            x: q.x,
+            y: q.y,
+            z: Fp2::one(),
+        };
+        let cleared = q.clear_cofactor();
+        assert!(cleared.is_on_curve());
+        assert!(!cleared.is_infinity());
+        assert!(cleared.is_in_subgroup_naive());
+
+        // Points already in G2 stay there. The map multiplies by a multiple
+        // of h2 rather than acting as the identity on G2, so the generator
+        // is not fixed.
+        let g = G2::generator().clear_cofactor();
+        assert!(!g.is_infinity());
+        assert!(g.is_in_subgroup_naive());
+
+        // Exceptional SvdW inputs still land on the curve
+        assert!(map_to_curve_svdw(&Fp2::zero()).is_on_curve());
+    }
+
+    #[test]
+    fn test_generator_encoding() {
+        // EIP-197: x_im || x_re || y_im || y_re
+        let words = [
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        ];
+        let mut expected = [0u8; 128];
+        for (i, w) in words.iter().enumerate() {
+            let bytes = BigUint::parse_bytes(w.as_bytes(), 16)
+                .unwrap()
+                .to_bytes_be();
+            expected[32 * i + 32 - bytes.len()..32 * (i + 1)].copy_from_slice(&bytes);
+        }
+
+        let g = affine(&G2::generator());
+        assert_eq!(g.to_uncompressed(), expected);
+        assert_eq!(G2Affine::from_uncompressed(&expected), Ok(g));
+    }
+
+    #[test]
+    fn test_encoding_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let mut points = vec![G2Affine::infinity(), affine(&G2::generator())];
+        points.push(affine(&-G2::generator()));
+        points.push(affine(
+            &G2::generator().mul_scalar(&rng.gen::<u64>().into()),
+        ));
+        for q in &points {
+            assert_eq!(G2Affine::from_bytes(&q.to_uncompressed()), Ok(q.clone()));
+            assert_eq!(G2Affine::from_bytes(&q.to_compressed()), Ok(q.clone()));
+        }
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_bellman_vectors() {
+        // pairing_ce's G2Uncompressed of [2]G2 (coordinates from py_ecc's
+        // bn128.double(G2)), x.c1 || x.c0 || y.c1 || y.c0, and of the point
+        // at infinity, whose first byte carries only the 0x40 flag
+        let two: [u8; 128] = hex(
+            "203e205db4f19b37b60121b83a7333706db86431c6d835849957ed8c3928ad79\
+             27dc7234fd11d3e8c36c59277c3e6f149d5cd3cfa9a62aee49f8130962b4b3b9\
+             195e8aa5b7827463722b8c153931579d3505566b4edf48d498e185f0509de152\
+             04bb53b8977e5f92a0bc372742c4830944a59b4fe6b1c0466e2a6dad122b5d2e",
+        )
+        .try_into()
+        .unwrap();
+        let q = G2::generator().double();
+        assert_eq!(q.to_bellman_bytes(), two);
+        assert_eq!(affine(&G2::from_bellman_bytes(&two).unwrap()), affine(&q));
+
+        let infinity: [u8; 128] = hex(&format!("40{}", "00".repeat(127))).try_into().unwrap();
+        assert_eq!(G2::infinity().to_bellman_bytes(), infinity);
+        assert!(G2::from_bellman_bytes(&infinity).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_bellman_encoding() {
+        let g = G2::generator();
+        let bytes = g.to_bellman_bytes();
+        assert_eq!(bytes, affine(&g).to_uncompressed());
+        // x.c1 comes first
+        assert_eq!(
+            Fp::from_bytes_be(&bytes[..32]).unwrap(),
+            "0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+                .parse()
+                .unwrap()
+        );
+        let decoded = G2::from_bellman_bytes(&bytes).unwrap();
+        assert_eq!(affine(&decoded), affine(&g));
+
+        let mut infinity = [0u8; 128];
+        infinity[0] = COMPRESSED_INFINITY_FLAG;
+        assert_eq!(G2::infinity().to_bellman_bytes(), infinity);
+        assert!(G2::from_bellman_bytes(&infinity).unwrap().is_infinity());
+
+        assert_eq!(
+            G2::from_bellman_bytes(&[0u8; 128]),
+            Err(DecodeError::NotOnCurve)
+        );
+        let q = non_subgroup_point();
+        assert_eq!(
+            G2::from_bellman_bytes(&q.to_uncompressed()),
+            Err(DecodeError::NotInSubgroup)
+        );
+    }
+
+    #[test]
+    fn test_ark_encoding() {
+        let g = G2::generator();
+        let bytes = g.to_ark_bytes();
+        let (x, _) = g.to_affine();
+        let mut c0 = x.c0.to_bytes_be();
+        let mut c1 = x.c1.to_bytes_be();
+        c0.reverse();
+        c1.reverse();
+        assert_eq!(bytes[..32], c0);
+        assert_eq!(bytes[32..63], c1[..31]);
+        assert_eq!(bytes[63] & !COMPRESSED_SIGN_FLAG, c1[31]);
+
+        // Negation flips only the sign flag
+        let neg = (-g.clone()).to_ark_bytes();
+        assert_eq!(neg[63] ^ bytes[63], COMPRESSED_SIGN_FLAG);
+        assert_eq!(neg[..63], bytes[..63]);
+
+        let mut infinity = [0u8; 64];
+        infinity[63] = COMPRESSED_INFINITY_FLAG;
+        assert_eq!(G2::infinity().to_ark_bytes(), infinity);
+
+        for q in [G2::infinity(), g.clone(), -g.double()] {
+            let decoded = G2::from_ark_bytes(&q.to_ark_bytes()).unwrap();
+            assert_eq!(affine(&decoded), affine(&q));
+        }
+        let q = non_subgroup_point();
+        assert_eq!(
+            G2::from_ark_bytes(&G2::from(q).to_ark_bytes()),
+            Err(DecodeError::NotInSubgroup)
+        );
+    }
+
+    #[test]
+    fn test_decoding_errors() {
+        let q = non_subgroup_point();
+        assert!(q.is_on_curve());
+        assert_eq!(
+            G2Affine::from_uncompressed(&q.to_uncompressed()),
+            Err(DecodeError::NotInSubgroup)
+        );
+        assert_eq!(
+            G2Affine::from_compressed(&q.to_compressed()),
+            Err(DecodeError::NotInSubgroup)
+        );
+
+        let mut bad = affine(&G2::generator());
+        bad.y = &bad.y + &Fp2::one();
+        assert_eq!(
+            G2Affine::from_uncompressed(&bad.to_uncompressed()),
+            Err(DecodeError::NotOnCurve)
+        );
+
+        assert_eq!(
+            G2Affine::from_bytes(&[0u8; 65]),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let p = G2::generator().double();
 
         let res0 = p.mul_scalar(&BigUint::zero());
         assert!(res0.is_infinity());
@@ -250,4 +1719,68 @@ mod tests {
         let res3 = p.mul_scalar(&3u32.to_biguint().unwrap());
         assert_eq!(res3.to_affine(), p.add(&p).add(&p).to_affine());
     }
+
+    #[test]
+    fn test_fixed_base() {
+        let mut rng = rand::thread_rng();
+        let p = G2::generator().mul_scalar(&BigUint::from(rng.gen::<u128>()));
+        for w in [1, 4, 7] {
+            let table = G2FixedBase::new(&p, w);
+            assert_eq!(table.table.len(), 256usize.div_ceil(w) * ((1 << w) - 1));
+            for k in [
+                BigUint::zero(),
+                BigUint::from(rng.gen::<u128>()),
+                &*GROUP_ORDER - 1u32,
+                &*GROUP_ORDER + 5u32,
+            ] {
+                assert_eq!(affine(&table.mul(&k)), affine(&p.mul_scalar(&k)));
+            }
+        }
+        assert!(G2::infinity()
+            .precompute_fixed_base(4)
+            .mul(&BigUint::from(7u32))
+            .is_infinity());
+    }
+
+    #[test]
+    fn test_mul_gen() {
+        let mut rng = rand::thread_rng();
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            -&Scalar::one(),
+            &Scalar::from(rng.gen::<u128>()) * &Scalar::from(rng.gen::<u128>()),
+        ];
+        for s in &scalars {
+            let expected = G2::generator().mul_scalar(s.as_biguint());
+            assert_eq!(affine(&G2::mul_gen(s)), affine(&expected));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        // EIP-197 generator coordinates
+        let json = serde_json::to_string(&G2::generator()).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"x":{"c0":"0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed","#,
+                r#""c1":"0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"},"#,
+                r#""y":{"c0":"0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa","#,
+                r#""c1":"0x090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"},"#,
+                r#""infinity":false}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<G2>(&json).unwrap(), G2::generator());
+
+        let mut rng = rand::thread_rng();
+        let q = random_point(&mut rng);
+        let json = serde_json::to_string(&q).unwrap();
+        let r: G2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(r.to_affine(), q.to_affine());
+
+        let json = serde_json::to_string(&non_subgroup_point()).unwrap();
+        assert!(serde_json::from_str::<G2Affine>(&json).is_err());
+    }
 }
@@ -0,0 +1,367 @@
+use crate::g1::{multi_scalar_mul, G1};
+use crate::g2::G2;
+use crate::gt::Gt;
+use crate::pairing::{multi_miller_loop_precomputed, pairing, G2Precomputed};
+use crate::GROUP_ORDER;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// A Groth16 verifying key
+///
+/// `ic[0]` is the constant term of the public input commitment and
+/// `ic[i + 1]` the base for the i-th public input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g2: G2,
+    pub ic: Vec<G1>,
+}
+
+/// A verifying key with the fixed parts of the pairing check precomputed:
+/// e(α, β) and the Miller loop lines for -γ and -δ
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedVerifyingKey {
+    pub alpha_beta: Gt,
+    pub neg_gamma_g2: G2Precomputed,
+    pub neg_delta_g2: G2Precomputed,
+    pub ic: Vec<G1>,
+}
+
+/// A Groth16 proof (A, B, C)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+}
+
+pub fn prepare_verifying_key(vk: &VerifyingKey) -> PreparedVerifyingKey {
+    PreparedVerifyingKey {
+        alpha_beta: pairing(&vk.alpha_g1, &vk.beta_g2),
+        neg_gamma_g2: (-vk.gamma_g2.clone()).precompute(),
+        neg_delta_g2: (-vk.delta_g2.clone()).precompute(),
+        ic: vk.ic.clone(),
+    }
+}
+
+/// Checks e(A, B) == e(α, β) · e(∑ xᵢ·ICᵢ, γ) · e(C, δ), written as
+/// e(A, B) · e(∑ xᵢ·ICᵢ, -γ) · e(C, -δ) == e(α, β) so that a single
+/// multi-Miller loop and final exponentiation are needed.
+///
+/// Returns false if the number of public inputs does not match the key,
+/// an input is not reduced modulo r, or a proof point is invalid.
+pub fn verify_proof(pvk: &PreparedVerifyingKey, proof: &Proof, public_inputs: &[BigUint]) -> bool {
+    if public_inputs.len() + 1 != pvk.ic.len() || public_inputs.iter().any(|x| *x >= *GROUP_ORDER) {
+        return false;
+    }
+    if !proof.a.is_on_curve()
+        || !proof.c.is_on_curve()
+        || !proof.b.is_on_curve()
+        || !proof.b.is_in_subgroup()
+    {
+        return false;
+    }
+
+    let acc = pvk.ic[0].add(&multi_scalar_mul(&pvk.ic[1..], public_inputs));
+    multi_miller_loop_precomputed(&[
+        (&proof.a, &proof.b.precompute()),
+        (&acc, &pvk.neg_gamma_g2),
+        (&proof.c, &pvk.neg_delta_g2),
+    ])
+    .final_exp()
+        == pvk.alpha_beta
+}
+
+/// snarkjs JSON layouts (`verification_key.json` and `proof.json`). Points
+/// are arrays of decimal strings in Jacobian coordinates, and Fp2 values
+/// are [c0, c1] pairs. Other fields such as "protocol" are ignored.
+#[cfg(feature = "serde")]
+mod snarkjs {
+    use super::{Proof, VerifyingKey};
+    use crate::fp::Fp;
+    use crate::fp2::Fp2;
+    use crate::g1::{DecodeError, G1};
+    use crate::g2::G2;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::de::Error;
+
+    type G1Repr = [String; 3];
+    type G2Repr = [[String; 2]; 3];
+
+    #[derive(serde::Deserialize)]
+    struct VerifyingKeyRepr {
+        vk_alpha_1: G1Repr,
+        vk_beta_2: G2Repr,
+        vk_gamma_2: G2Repr,
+        vk_delta_2: G2Repr,
+        #[serde(rename = "IC")]
+        ic: Vec<G1Repr>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ProofRepr {
+        pi_a: G1Repr,
+        pi_b: G2Repr,
+        pi_c: G1Repr,
+    }
+
+    fn fp(s: &str) -> Result<Fp, DecodeError> {
+        s.parse().map_err(|_| DecodeError::InvalidFieldElement)
+    }
+
+    fn fp2(c: &[String; 2]) -> Result<Fp2, DecodeError> {
+        Ok(Fp2::new(fp(&c[0])?, fp(&c[1])?))
+    }
+
+    // snarkjs gives Jacobian coordinates, so build the point from them
+    #[allow(deprecated)]
+    fn g1(c: &G1Repr) -> Result<G1, DecodeError> {
+        let p = G1 {
+            x: fp(&c[0])?,
+            y: fp(&c[1])?,
+            z: fp(&c[2])?,
+        };
+        if !p.is_on_curve() {
+            return Err(DecodeError::NotOnCurve);
+        }
+        Ok(p)
+    }
+
+    #[allow(deprecated)]
+    fn g2(c: &G2Repr) -> Result<G2, DecodeError> {
+        let q = G2 {
+            x: fp2(&c[0])?,
+            y: fp2(&c[1])?,
+            z: fp2(&c[2])?,
+        };
+        if !q.is_on_curve() {
+            return Err(DecodeError::NotOnCurve);
+        }
+        if !q.is_in_subgroup() {
+            return Err(DecodeError::NotInSubgroup);
+        }
+        Ok(q)
+    }
+
+    impl<'de> serde::Deserialize<'de> for VerifyingKey {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = VerifyingKeyRepr::deserialize(deserializer)?;
+            let vk = (|| {
+                Ok::<_, DecodeError>(VerifyingKey {
+                    alpha_g1: g1(&repr.vk_alpha_1)?,
+                    beta_g2: g2(&repr.vk_beta_2)?,
+                    gamma_g2: g2(&repr.vk_gamma_2)?,
+                    delta_g2: g2(&repr.vk_delta_2)?,
+                    ic: repr.ic.iter().map(g1).collect::<Result<_, _>>()?,
+                })
+            })();
+            vk.map_err(D::Error::custom)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Proof {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = ProofRepr::deserialize(deserializer)?;
+            let proof = (|| {
+                Ok::<_, DecodeError>(Proof {
+                    a: g1(&repr.pi_a)?,
+                    b: g2(&repr.pi_b)?,
+                    c: g1(&repr.pi_c)?,
+                })
+            })();
+            proof.map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+
+    fn mod_r(x: BigUint) -> BigUint {
+        x % &*GROUP_ORDER
+    }
+
+    /// A verifying key from known trapdoor scalars, and a proof for the
+    /// given public inputs simulated with the trapdoor: with A = [a]G1,
+    /// B = [b]G2 and C = [(ab - αβ - γ·∑xᵢkᵢ) / δ]G1 the pairing equation
+    /// holds by construction.
+    fn simulated_proof(inputs: &[BigUint]) -> (VerifyingKey, Proof) {
+        let r = &*GROUP_ORDER;
+        let (alpha, beta, gamma, delta) = (
+            BigUint::from(11u32),
+            BigUint::from(13u32),
+            BigUint::from(17u32),
+            BigUint::from(19u32),
+        );
+        let ks: Vec<BigUint> = (0..=inputs.len() as u32)
+            .map(|i| BigUint::from(23 + i))
+            .collect();
+        let (a, b) = (BigUint::from(29u32), BigUint::from(31u32));
+
+        let g1 = G1::generator();
+        let g2 = G2::generator();
+        let vk = VerifyingKey {
+            alpha_g1: g1.mul_scalar(&alpha),
+            beta_g2: g2.mul_scalar(&beta),
+            gamma_g2: g2.mul_scalar(&gamma),
+            delta_g2: g2.mul_scalar(&delta),
+            ic: ks.iter().map(|k| g1.mul_scalar(k)).collect(),
+        };
+
+        let sum = inputs
+            .iter()
+            .zip(&ks[1..])
+            .fold(ks[0].clone(), |acc, (x, k)| mod_r(acc + x * k));
+        let numerator = mod_r(&a * &b + r * r - &alpha * &beta - &gamma * &sum);
+        let delta_inv = delta.modpow(&(r - 2u32), r);
+        let c = mod_r(numerator * delta_inv);
+
+        let proof = Proof {
+            a: g1.mul_scalar(&a),
+            b: g2.mul_scalar(&b),
+            c: g1.mul_scalar(&c),
+        };
+        (vk, proof)
+    }
+
+    #[test]
+    fn test_rejects_malformed_inputs() {
+        let inputs = [BigUint::from(33u32)];
+        let (vk, proof) = simulated_proof(&inputs);
+        let pvk = prepare_verifying_key(&vk);
+
+        assert!(!verify_proof(&pvk, &proof, &[]));
+        assert!(!verify_proof(
+            &pvk,
+            &proof,
+            &[BigUint::zero(), BigUint::zero()]
+        ));
+        assert!(!verify_proof(&pvk, &proof, &[GROUP_ORDER.clone() + 33u32]));
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let inputs = [BigUint::from(33u32), BigUint::from(7u32)];
+        let (vk, proof) = simulated_proof(&inputs);
+        let pvk = prepare_verifying_key(&vk);
+
+        assert!(verify_proof(&pvk, &proof, &inputs));
+        assert!(!verify_proof(
+            &pvk,
+            &proof,
+            &[BigUint::from(34u32), BigUint::from(7u32)]
+        ));
+
+        let mut bad = proof.clone();
+        bad.c = bad.c.double();
+        assert!(!verify_proof(&pvk, &bad, &inputs));
+    }
+
+    #[cfg(feature = "serde")]
+    fn g1_json(p: &G1) -> String {
+        let (x, y) = p.to_affine();
+        format!(r#"["{}", "{}", "1"]"#, x, y)
+    }
+
+    #[cfg(feature = "serde")]
+    fn g2_json(q: &G2) -> String {
+        let (x, y) = q.to_affine();
+        format!(
+            r#"[["{}", "{}"], ["{}", "{}"], ["1", "0"]]"#,
+            x.c0, x.c1, y.c0, y.c1
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snarkjs_json() {
+        let inputs = [BigUint::from(33u32)];
+        let (vk, proof) = simulated_proof(&inputs);
+
+        let vk_json = format!(
+            r#"{{
+                "protocol": "groth16",
+                "curve": "bn128",
+                "nPublic": 1,
+                "vk_alpha_1": {},
+                "vk_beta_2": {},
+                "vk_gamma_2": {},
+                "vk_delta_2": {},
+                "IC": [{}, {}]
+            }}"#,
+            g1_json(&vk.alpha_g1),
+            g2_json(&vk.beta_g2),
+            g2_json(&vk.gamma_g2),
+            g2_json(&vk.delta_g2),
+            g1_json(&vk.ic[0]),
+            g1_json(&vk.ic[1]),
+        );
+        let proof_json = format!(
+            r#"{{"pi_a": {}, "pi_b": {}, "pi_c": {}, "protocol": "groth16"}}"#,
+            g1_json(&proof.a),
+            g2_json(&proof.b),
+            g1_json(&proof.c),
+        );
+
+        let parsed_vk: VerifyingKey = serde_json::from_str(&vk_json).unwrap();
+        let parsed_proof: Proof = serde_json::from_str(&proof_json).unwrap();
+        assert_eq!(parsed_vk.alpha_g1.to_affine(), vk.alpha_g1.to_affine());
+        assert_eq!(parsed_vk.delta_g2.to_affine(), vk.delta_g2.to_affine());
+        assert_eq!(parsed_vk.ic.len(), 2);
+        assert_eq!(parsed_vk.ic[1].to_affine(), vk.ic[1].to_affine());
+        assert_eq!(parsed_proof.b.to_affine(), proof.b.to_affine());
+        assert_eq!(parsed_proof.c.to_affine(), proof.c.to_affine());
+
+        // Points off the curve are rejected
+        let bad = proof_json.replacen(&g1_json(&proof.a), r#"["1", "3", "1"]"#, 1);
+        assert!(serde_json::from_str::<Proof>(&bad).is_err());
+        let bad = vk_json.replacen(r#""1", "0""#, r#""2", "0""#, 1);
+        assert!(serde_json::from_str::<VerifyingKey>(&bad).is_err());
+    }
+
+    /// A proof of knowledge of x with x³ + x + 5 = 35, see
+    /// tests/fixtures/groth16/README.md
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snarkjs_fixture() {
+        use crate::fp::Fp;
+        use crate::fp12::Fp12;
+        use crate::fp2::Fp2;
+        use crate::fp6::Fp6;
+        use alloc::string::String;
+
+        let vk_json = include_str!("../tests/fixtures/groth16/verification_key.json");
+        let vk: VerifyingKey = serde_json::from_str(vk_json).unwrap();
+        let proof: Proof =
+            serde_json::from_str(include_str!("../tests/fixtures/groth16/proof.json")).unwrap();
+        let public: Vec<String> =
+            serde_json::from_str(include_str!("../tests/fixtures/groth16/public.json")).unwrap();
+        let inputs: Vec<BigUint> = public.iter().map(|x| x.parse().unwrap()).collect();
+        assert_eq!(inputs, [BigUint::from(35u32)]);
+
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &proof, &inputs));
+        assert!(!verify_proof(&pvk, &proof, &[BigUint::from(36u32)]));
+        let mut bad = proof.clone();
+        bad.a = -bad.a;
+        assert!(!verify_proof(&pvk, &bad, &inputs));
+
+        // vk_alphabeta_12 holds e(α, β) under the textbook final exponent
+        let value: serde_json::Value = serde_json::from_str(vk_json).unwrap();
+        let ab = &value["vk_alphabeta_12"];
+        let fp2 = |c: &serde_json::Value| {
+            let fp = |x: &serde_json::Value| x.as_str().unwrap().parse::<Fp>().unwrap();
+            Fp2::new(fp(&c[0]), fp(&c[1]))
+        };
+        let fp6 = |c: &serde_json::Value| Fp6::new(fp2(&c[0]), fp2(&c[1]), fp2(&c[2]));
+        assert_eq!(
+            *pvk.alpha_beta.to_textbook().inner(),
+            Fp12::new(fp6(&ab[0]), fp6(&ab[1]))
+        );
+    }
+}
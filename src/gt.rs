@@ -0,0 +1,167 @@
+use crate::fp12::Fp12;
+use core::fmt;
+use core::ops::Mul;
+#[cfg(feature = "alloc")]
+use num_bigint::BigUint;
+
+/// m⁻¹ mod r for the extra exponent m = 2u(6u² + 3u + 1) of the final
+/// exponentiation, as little-endian 64-bit limbs
+const M_INV: [u64; 4] = [
+    0xb8ca0b2d36636f22,
+    0xcc37a73fec2bc5e9,
+    0x048b6e193fd84104,
+    0x30644e72e131a029,
+];
+
+/// An element of the target group GT, the order-r subgroup of Fp12*
+/// that pairings map into
+///
+/// Only values produced by the pairing (and products, powers and inverses
+/// of them) can be built, so GT elements cannot be mixed up with arbitrary
+/// Fp12 values.
+///
+/// The pairing of this crate is the textbook optimal Ate pairing raised to
+/// the power m = 2u(6u² + 3u + 1), see `pairing::final_exponentiation`.
+/// Equations between pairings hold either way, but the Fp12 coefficients
+/// differ from those of implementations that exponentiate by exactly
+/// (p¹² - 1)/r, such as py_ecc. `to_textbook` converts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gt(Fp12);
+
+impl Gt {
+    /// Wraps the output of the final exponentiation
+    pub(crate) fn new(f: Fp12) -> Self {
+        Gt(f)
+    }
+
+    /// The identity element
+    pub fn one() -> Self {
+        Gt(Fp12::one())
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.0 == Fp12::one()
+    }
+
+    /// The underlying Fp12 value
+    pub fn inner(&self) -> &Fp12 {
+        &self.0
+    }
+
+    /// self^exp, using cyclotomic squarings
+    #[cfg(feature = "alloc")]
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        self.pow_vartime(&exp.to_u64_digits())
+    }
+
+    /// self^exp for an exponent given as little-endian 64-bit limbs, see
+    /// `Fp::pow_vartime`
+    pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+        // Cyclotomic squaring is only correct for f with f^(p^6 + 1) == 1
+        debug_assert!(
+            self.0.is_cyclotomic(),
+            "Gt::pow: element is not in the cyclotomic subgroup"
+        );
+        Gt(self.0.cyclotomic_exp_vartime(exp))
+    }
+
+    /// The inverse, which for GT is just the Fp12 conjugate
+    pub fn inv(&self) -> Self {
+        Gt(self.0.conjugate())
+    }
+
+    /// self^(m⁻¹ mod r): the value the textbook final exponent (p¹² - 1)/r
+    /// gives for the same Miller loop output, for comparison with other
+    /// implementations. Costs one exponentiation by a 254-bit scalar.
+    pub fn to_textbook(&self) -> Self {
+        self.pow_vartime(&M_INV)
+    }
+}
+
+/// The default is the identity
+impl Default for Gt {
+    fn default() -> Self {
+        Gt::one()
+    }
+}
+
+impl fmt::Display for Gt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Mul for Gt {
+    type Output = Gt;
+    fn mul(self, rhs: Gt) -> Gt {
+        &self * &rhs
+    }
+}
+
+impl<'b> Mul<&'b Gt> for &Gt {
+    type Output = Gt;
+    fn mul(self, rhs: &'b Gt) -> Gt {
+        debug_assert!(
+            self.0.is_cyclotomic() && rhs.0.is_cyclotomic(),
+            "Gt::mul: element is not in the cyclotomic subgroup"
+        );
+        Gt(&self.0 * &rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::g1::G1;
+    use crate::g2::G2;
+    use crate::pairing::pairing;
+    use crate::GROUP_ORDER;
+
+    #[test]
+    fn test_group_ops() {
+        let e = pairing(&G1::generator(), &G2::generator());
+        assert!(!e.is_identity());
+        assert!(Gt::one().is_identity());
+
+        assert!((&e * &e.inv()).is_identity());
+        assert_eq!(e.pow(&BigUint::from(3u32)), &(&e * &e) * &e);
+        assert_eq!(e.pow(&BigUint::from(0u32)), Gt::one());
+        assert_eq!(e.clone() * Gt::one(), e);
+        assert_eq!(
+            e.inner().pow(&BigUint::from(5u32)),
+            *e.pow(&BigUint::from(5u32)).inner()
+        );
+    }
+
+    #[test]
+    fn test_to_textbook() {
+        // Raising back to m undoes the conversion
+        let u = BigUint::from(crate::BN_PARAM);
+        let m = BigUint::from(2u32) * &u * (BigUint::from(6u32) * &u * &u + 3u32 * &u + 1u32);
+        let r = &*GROUP_ORDER;
+        assert_eq!(m.modpow(&(r - 2u32), r).to_u64_digits(), M_INV);
+        let e = pairing(&G1::generator(), &G2::generator());
+        assert_eq!(e.to_textbook().pow(&m), e);
+        assert_ne!(e.to_textbook(), e);
+        assert!(Gt::one().to_textbook().is_identity());
+    }
+
+    #[test]
+    fn test_order() {
+        let e = pairing(&G1::generator(), &G2::generator());
+        assert!(e.pow(&GROUP_ORDER).is_identity());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not in the cyclotomic subgroup")]
+    fn test_pow_rejects_non_cyclotomic() {
+        use crate::fp::Fp;
+        use crate::fp2::Fp2;
+        use crate::fp6::Fp6;
+
+        let c = Fp2::new(Fp::new(2u32.into()), Fp::zero());
+        let f = Fp12::new(Fp6::new(c, Fp2::zero(), Fp2::zero()), Fp6::zero());
+        Gt(f).pow(&BigUint::from(3u32));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// SHA-256 input block size in bytes
+const B_IN_BYTES: usize = 64;
+/// SHA-256 output size in bytes
+const B_OUT_BYTES: usize = 32;
+
+/// Bytes hashed per field element: ceil((ceil(log2(p)) + k) / 8) with
+/// security parameter k = 128
+const L: usize = 48;
+
+/// expand_message_xmd from RFC 9380 section 5.3.1, instantiated with SHA-256.
+/// Domain separation tags longer than 255 bytes are first hashed as in
+/// section 5.3.3. Panics if `len_in_bytes` exceeds 255 * 32.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(B_OUT_BYTES);
+    assert!(
+        ell <= 255 && len_in_bytes <= u16::MAX as usize,
+        "expand_message_xmd: requested too many bytes"
+    );
+
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = Sha256::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &long_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b0 = Sha256::new()
+        .chain_update([0u8; B_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut bi = Sha256::new()
+        .chain_update(b0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+    let mut uniform_bytes = bi.to_vec();
+    for i in 2..=ell {
+        let mut xored = [0u8; B_OUT_BYTES];
+        for (x, (a, b)) in xored.iter_mut().zip(b0.iter().zip(bi.iter())) {
+            *x = a ^ b;
+        }
+        bi = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&bi);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// hash_to_field from RFC 9380 section 5.2 for Fp: expands the message to
+/// `count * 48` bytes and reduces each 48-byte big-endian chunk modulo p.
+/// The 128 extra bits make the bias of the reduction negligible. Each chunk
+/// is zero-extended to 64 bytes for `Fp::from_bytes_wide`.
+pub fn hash_to_fp(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fp> {
+    expand_message_xmd(msg, dst, count * L)
+        .chunks(L)
+        .map(|chunk| {
+            let mut wide = [0u8; 64];
+            wide[64 - L..].copy_from_slice(chunk);
+            Fp::from_bytes_wide(&wide)
+        })
+        .collect()
+}
+
+/// hash_to_field for Fp2 (extension degree m = 2): each element takes two
+/// consecutive 48-byte chunks, the first giving c0 and the second c1
+pub fn hash_to_fp2(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fp2> {
+    hash_to_fp(msg, dst, 2 * count)
+        .chunks(2)
+        .map(|c| Fp2::new(c[0], c[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // RFC 9380 appendix K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex(&expand_message_xmd(b"", dst, 0x20)),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex(&expand_message_xmd(b"abc", dst, 0x20)),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+        assert_eq!(
+            hex(&expand_message_xmd(b"abcdef0123456789", dst, 0x20)),
+            "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"
+        );
+        assert_eq!(
+            hex(&expand_message_xmd(b"", dst, 0x80)),
+            concat!(
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe",
+                "e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18",
+                "eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc",
+                "c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hash_to_fp() {
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+        let u = hash_to_fp(b"", dst, 2);
+        assert_eq!(
+            format!("{:x}", u[0]),
+            "2f87b81d9d6ef05ad4d249737498cc27e1bd485dca804487844feb3c67c1a9b5"
+        );
+        assert_eq!(
+            format!("{:x}", u[1]),
+            "06de2d0d7c0d9c7a5a6c0b74675e7543f5b98186b5dbf831067449000b2b1f8e"
+        );
+        assert_ne!(hash_to_fp(b"a", dst, 1), hash_to_fp(b"b", dst, 1));
+    }
+
+    #[test]
+    fn test_hash_to_fp2() {
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+        let u = hash_to_fp2(b"", dst, 2);
+        assert_eq!(u.len(), 2);
+        assert_eq!(
+            format!("{:x}", u[0]),
+            concat!(
+                "2c85988ecf26034a6d6c495c467150aeaead51fceb623aa99b0433275c8952c7 + ",
+                "182126b31e6df7cf33844bf16a92f42072ee47f80539dace68dbfc3380d1fcbd*u"
+            )
+        );
+        assert_eq!(hash_to_fp(b"", dst, 4)[2], u[1].c0);
+    }
+}
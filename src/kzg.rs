@@ -0,0 +1,157 @@
+use crate::g1::{multi_scalar_mul, G1};
+use crate::g2::G2;
+use crate::pairing::pairing_check;
+use crate::GROUP_ORDER;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Trusted setup from a known secret τ: returns [τ⁰G1, τ¹G1, ..., τᵈG1],
+/// [τ]G2 and G2. τ must be discarded afterwards; this is meant for tests
+/// and single-party setups. The powers of τ are secret, so every product
+/// goes through the `mul_scalar` ladders.
+pub fn setup(max_degree: usize, tau: &BigUint) -> (Vec<G1>, G2, G2) {
+    let g1 = G1::generator();
+    let mut srs = Vec::with_capacity(max_degree + 1);
+    let mut power = BigUint::from(1u32);
+    for _ in 0..=max_degree {
+        srs.push(g1.mul_scalar(&power));
+        power = (power * tau) % &*GROUP_ORDER;
+    }
+    let g2 = G2::generator();
+    (srs, g2.mul_scalar(tau), g2)
+}
+
+/// Commitment ∑ coeffsᵢ·srsᵢ to the polynomial with the given coefficients
+/// (lowest degree first)
+pub fn commit(srs: &[G1], coeffs: &[BigUint]) -> G1 {
+    assert!(
+        coeffs.len() <= srs.len(),
+        "commit: polynomial degree exceeds the setup"
+    );
+    multi_scalar_mul(&srs[..coeffs.len()], coeffs)
+}
+
+/// Evaluates the polynomial at z and returns (f(z), π) where π commits to
+/// the quotient q(x) = (f(x) - f(z)) / (x - z)
+pub fn open(srs: &[G1], coeffs: &[BigUint], z: &BigUint) -> (BigUint, G1) {
+    let r = &*GROUP_ORDER;
+    let z = z % r;
+
+    // Synthetic division by (x - z): the running Horner values are the
+    // quotient coefficients and the final one is f(z)
+    let mut quotient = vec![BigUint::zero(); coeffs.len().saturating_sub(1)];
+    let mut acc = BigUint::zero();
+    for (i, c) in coeffs.iter().enumerate().rev() {
+        acc = (acc * &z + c) % r;
+        if i > 0 {
+            quotient[i - 1] = acc.clone();
+        }
+    }
+
+    (acc, commit(srs, &quotient))
+}
+
+/// Checks an opening with one pairing check:
+/// e(C - [v]G1 + [z]π, G2) == e(π, [τ]G2)
+///
+/// The commitment and proof must be on the curve (G1 has cofactor 1) and
+/// the two SRS points in G2; anything else is rejected.
+pub fn verify(
+    g2_tau: &G2,
+    g2: &G2,
+    commitment: &G1,
+    z: &BigUint,
+    value: &BigUint,
+    proof: &G1,
+) -> bool {
+    if !commitment.is_on_curve() || !proof.is_on_curve() {
+        return false;
+    }
+    if ![g2_tau, g2]
+        .iter()
+        .all(|q| q.is_on_curve() && q.is_in_subgroup())
+    {
+        return false;
+    }
+    let g1 = G1::generator();
+    let lhs = commitment
+        .add(&-g1.mul_scalar_glv(value))
+        .add(&proof.mul_scalar_glv(z));
+    pairing_check(&[(&lhs, g2), (&-*proof, g2_tau)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use crate::fp2::Fp2;
+
+    fn polynomial() -> Vec<BigUint> {
+        // Degree 5
+        [3u32, 1, 4, 1, 5, 9]
+            .iter()
+            .map(|&c| BigUint::from(c))
+            .collect()
+    }
+
+    #[test]
+    fn test_open_quotient() {
+        let tau = BigUint::from(123456789u32);
+        let (srs, _, _) = setup(5, &tau);
+        let coeffs = polynomial();
+        let z = BigUint::from(17u32);
+        let (value, proof) = open(&srs, &coeffs, &z);
+
+        let expected = coeffs
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, c| (acc * &z + c) % &*GROUP_ORDER);
+        assert_eq!(value, expected);
+
+        // With τ known, C - [v]G1 = [τ - z]π
+        let c = commit(&srs, &coeffs);
+        let lhs = c.add(&-G1::generator().mul_scalar(&value));
+        assert_eq!(lhs.to_affine(), proof.mul_scalar(&(&tau - &z)).to_affine());
+    }
+
+    #[test]
+    fn test_commit_open_verify() {
+        let tau = BigUint::from(123456789u32);
+        let (srs, g2_tau, g2) = setup(5, &tau);
+        let coeffs = polynomial();
+        let c = commit(&srs, &coeffs);
+        let z = BigUint::from(17u32);
+        let (value, proof) = open(&srs, &coeffs, &z);
+        assert!(verify(&g2_tau, &g2, &c, &z, &value, &proof));
+        assert!(!verify(&g2_tau, &g2, &c, &z, &(&value + 1u32), &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_points() {
+        let tau = BigUint::from(123456789u32);
+        let (srs, g2_tau, g2) = setup(5, &tau);
+        let coeffs = polynomial();
+        let c = commit(&srs, &coeffs);
+        let z = BigUint::from(17u32);
+        let (value, proof) = open(&srs, &coeffs, &z);
+
+        let off_curve = G1::from_affine_unchecked(Fp::one(), Fp::from(3u32));
+        assert!(!verify(&g2_tau, &g2, &off_curve, &z, &value, &proof));
+        assert!(!verify(&g2_tau, &g2, &c, &z, &value, &off_curve));
+
+        // A point on the twist outside G2
+        let mut x = Fp2::one();
+        let q = loop {
+            let rhs = &(&(&x * &x) * &x) + &G2::get_b();
+            if let Some(y) = rhs.sqrt() {
+                break G2::from_affine_unchecked(x, y);
+            }
+            x = &x + &Fp2::one();
+        };
+        assert!(q.is_on_curve() && !q.is_in_subgroup());
+        assert!(!verify(&q, &g2, &c, &z, &value, &proof));
+        assert!(!verify(&g2_tau, &q, &c, &z, &value, &proof));
+    }
+}
@@ -1,7 +1,98 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Without `alloc` only the field, curve and pairing core is built, see the
+// "no_std" section of the README
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod bls;
+#[cfg(feature = "alloc")]
+pub mod chaum_pedersen;
+mod constants;
+#[cfg(feature = "alloc")]
+pub mod eip196;
+#[cfg(feature = "alloc")]
+pub mod eip197;
+pub mod error;
 pub mod fp;
+pub mod fp12;
 pub mod fp2;
 pub mod fp6;
-pub mod fp12;
+// The coordinate fields of G1 and G2 are deprecated for code outside the
+// crate only; the arithmetic in these modules works on them directly.
+#[allow(deprecated)]
 pub mod g1;
+#[allow(deprecated)]
 pub mod g2;
+#[cfg(feature = "alloc")]
+pub mod groth16;
+pub mod gt;
+#[cfg(feature = "alloc")]
+pub mod hash_to_field;
+#[cfg(feature = "alloc")]
+pub mod kzg;
 pub mod pairing;
+#[cfg(feature = "alloc")]
+pub mod pedersen;
+#[cfg(feature = "alloc")]
+pub mod scalar;
+#[cfg(feature = "alloc")]
+pub mod scalar_field;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// The BN parameter u. The curve is defined by p = 36u⁴ + 36u³ + 24u² + 6u + 1
+/// and r = 36u⁴ + 36u³ + 18u² + 6u + 1.
+pub const BN_PARAM: u64 = 4965661367192848881;
+
+/// Signed-digit form of n, least significant digit first, with digits in
+/// {-1, 0, 1} and no two adjacent non-zero digits. N must exceed the bit
+/// length of n.
+pub(crate) const fn naf<const N: usize>(n: u128) -> [i8; N] {
+    wnaf(n, 2)
+}
+
+/// Width-w non-adjacent form of n, the `u128` counterpart of `g1::wnaf`
+/// for compile-time constants: odd digits below 2^(w-1) in absolute value,
+/// at most one nonzero in any w consecutive digits. n must be below 2^127
+/// and N must exceed its bit length.
+pub(crate) const fn wnaf<const N: usize>(mut n: u128, w: u32) -> [i8; N] {
+    let modulus = 1i128 << w;
+    let mut digits = [0i8; N];
+    let mut i = 0;
+    while n != 0 {
+        if n & 1 == 1 {
+            let low = (n & (modulus as u128 - 1)) as i128;
+            let d = if low >= modulus / 2 {
+                low - modulus
+            } else {
+                low
+            };
+            digits[i] = d as i8;
+            n = (n as i128 - d) as u128;
+        }
+        n >>= 1;
+        i += 1;
+    }
+    digits
+}
+
+/// The order r of the BN254 groups as little-endian 64-bit limbs, for the
+/// subgroup checks of the core that is built without `alloc`
+pub(crate) const GROUP_ORDER_LIMBS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+#[cfg(feature = "alloc")]
+lazy_static::lazy_static! {
+    /// The order r of the BN254 groups G1, G2 and GT
+    pub static ref GROUP_ORDER: num_bigint::BigUint = num_bigint::BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10
+    )
+    .unwrap();
+}
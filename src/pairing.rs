@@ -1,172 +1,715 @@
+use crate::fp::Fp;
 use crate::fp12::Fp12;
+use crate::fp2::Fp2;
 use crate::g1::G1;
-use crate::g2::G2;
-use num_bigint::BigUint;
-use num_traits::One;
-
-lazy_static::lazy_static! {
-    /// The BN parameter: 6u + 2 for BN254
-    /// For BN254: u = 4965661367192848881
-    static ref ATE_LOOP_COUNT: BigUint = BigUint::parse_bytes(
-        b"29793968203157093288",
-        10
-    ).unwrap();
-    
-    /// The final exponentiation power: (p^12 - 1) / r
-    static ref FINAL_EXP: BigUint = {
-        // p^12 - 1
-        let p = BigUint::parse_bytes(
-            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
-            10
-        ).unwrap();
-        let p12 = p.pow(12);
-        p12 - BigUint::one()
-        // In practice, this should be divided by r (the subgroup order)
-        // For a complete implementation, compute (p^12 - 1) / r
-    };
+use crate::g2::{G2Affine, G2, TWIST_B};
+use crate::gt::Gt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+/// The Ate loop count 6u + 2 in non-adjacent form, least significant digit
+/// first. It has 22 non-zero digits against 37 set bits in binary, so the
+/// loop does 21 addition steps instead of 36, for one extra doubling.
+const ATE_LOOP_NAF: [i8; 66] = crate::naf(6 * crate::BN_PARAM as u128 + 2);
+
+/// 1/2 in Fp
+const TWO_INV: Fp =
+    Fp::from_dec(b"10944121435919637611123202872628637544348155578648911831344518947322613104292");
+
+/// Nonzero coefficients of a line in the Miller loop, see
+/// `Fp12::mul_by_line_eval`. `ell_0` and `ell_w` still have to be scaled
+/// by the y and x coordinates of the G1 point the line is evaluated at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineCoeff {
+    pub ell_0: Fp2,
+    pub ell_w: Fp2,
+    pub ell_vw: Fp2,
 }
 
-/// Line function evaluation for Miller's algorithm
-fn line_function(_r: &G2, _q: &G2, _p: &G1) -> Fp12 {
-    // TODO: Implement proper line function
-    // This is a placeholder implementation
-    // The line function computes the equation of the line through R and Q
-    // evaluated at P
-    
-    Fp12::one()
+/// The line coefficients of the Miller loop for a fixed G2 point, in the
+/// order they are used. See `G2::precompute`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Precomputed {
+    pub coeffs: Vec<LineCoeff>,
 }
 
-/// Miller loop implementation
-/// Computes the Miller function f_{u,Q}(P)
-pub fn miller_loop(p: &G1, q: &G2) -> Fp12 {
-    if p.is_infinity() || q.is_infinity() {
-        return Fp12::one();
+#[cfg(feature = "alloc")]
+impl G2Precomputed {
+    /// Runs the G2 side of the Miller loop for `q`, recording every line.
+    /// The point at infinity gives no lines.
+    pub(crate) fn new(q: &G2) -> Self {
+        let mut coeffs = Vec::new();
+        if !q.is_infinity() {
+            let q = q.to_affine();
+            let neg_q = (q.0.clone(), -q.1.clone());
+            let mut r = MillerPoint::new(&q);
+            for_each_step(|digit| {
+                coeffs.push(match digit {
+                    0 => r.double_step(),
+                    1 => r.add_step(&q),
+                    _ => r.add_step(&neg_q),
+                });
+            });
+            for q in frobenius_points(&q) {
+                coeffs.push(r.add_step(&q));
+            }
+        }
+        G2Precomputed { coeffs }
+    }
+}
+
+/// The running point T = [i]Q of the Miller loop, in homogeneous projective
+/// coordinates (X : Y : Z) representing (X/Z, Y/Z) on the twist
+struct MillerPoint {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl MillerPoint {
+    fn new(q: &(Fp2, Fp2)) -> Self {
+        MillerPoint {
+            x: q.0.clone(),
+            y: q.1.clone(),
+            z: Fp2::one(),
+        }
+    }
+
+    /// Sets T = 2T and returns the tangent line at T
+    ///
+    /// Formulas from Costello, Lange and Naehrig, "Faster Pairing
+    /// Computations on Curves with High-Degree Twists". The line is
+    /// scaled by -2YZ to avoid inversions.
+    fn double_step(&mut self) -> LineCoeff {
+        let a = (&self.x * &self.y).mul_by_fp(&TWO_INV);
+        let b = &self.y * &self.y;
+        let c = &self.z * &self.z;
+        let e = &TWIST_B * &(&(&c + &c) + &c);
+        let f = &(&e + &e) + &e;
+        let g = (&b + &f).mul_by_fp(&TWO_INV);
+        let h = &(&(&self.y + &self.z) * &(&self.y + &self.z)) - &(&b + &c);
+        let i = &e - &b;
+        let j = &self.x * &self.x;
+        let e_sq = &e * &e;
+
+        self.x = &a * &(&b - &f);
+        self.y = &(&g * &g) - &(&(&e_sq + &e_sq) + &e_sq);
+        self.z = &b * &h;
+
+        LineCoeff {
+            ell_0: -h,
+            ell_w: &(&j + &j) + &j,
+            ell_vw: i,
+        }
+    }
+
+    /// Sets T = T + Q for an affine Q and returns the line through T and Q
+    fn add_step(&mut self, q: &(Fp2, Fp2)) -> LineCoeff {
+        let (qx, qy) = q;
+        let theta = &self.y - &(qy * &self.z);
+        let lambda = &self.x - &(qx * &self.z);
+        let c = &theta * &theta;
+        let d = &lambda * &lambda;
+        let e = &lambda * &d;
+        let f = &self.z * &c;
+        let g = &self.x * &d;
+        let h = &(&e + &f) - &(&g + &g);
+        let j = &(&theta * qx) - &(&lambda * qy);
+
+        self.x = &lambda * &h;
+        self.y = &(&theta * &(&g - &h)) - &(&e * &self.y);
+        self.z = &self.z * &e;
+
+        LineCoeff {
+            ell_0: lambda,
+            ell_w: -theta,
+            ell_vw: j,
+        }
+    }
+}
+
+/// f * l(P) for the line l with the given coefficients and affine P
+fn ell(f: &Fp12, coeff: &LineCoeff, p: &(Fp, Fp)) -> Fp12 {
+    let (px, py) = p;
+    f.mul_by_line_eval(
+        &coeff.ell_0.mul_by_fp(py),
+        &coeff.ell_w.mul_by_fp(px),
+        &coeff.ell_vw,
+    )
+}
+
+/// The value of a Miller loop before the final exponentiation.
+///
+/// Miller loop outputs of equal pairings generally differ, by factors
+/// that only the final exponentiation removes, so this type cannot be
+/// compared. `final_exp` turns it into a `Gt` value that can be. Products
+/// of Miller loop results exponentiate to products of pairings, which is
+/// how multi-pairings share one final exponentiation.
+#[derive(Clone, Debug)]
+pub struct MillerLoopResult(Fp12);
+
+impl MillerLoopResult {
+    /// The empty product, the Miller loop value of no pairs
+    pub fn one() -> Self {
+        MillerLoopResult(Fp12::one())
+    }
+
+    /// The raw Fp12 value. Only the tests read it, to compare Miller loops
+    /// computed in different ways; outside them such comparisons are the
+    /// mistake this type exists to prevent.
+    #[cfg(test)]
+    fn inner(&self) -> &Fp12 {
+        &self.0
+    }
+
+    /// Applies the final exponentiation, giving the pairing value
+    pub fn final_exp(self) -> Gt {
+        let f = final_exponentiation(&self.0);
+        f.debug_check_gt_element();
+        Gt::new(f)
+    }
+}
+
+/// The default is the empty product
+impl Default for MillerLoopResult {
+    fn default() -> Self {
+        MillerLoopResult::one()
+    }
+}
+
+impl Mul for MillerLoopResult {
+    type Output = MillerLoopResult;
+    fn mul(self, rhs: MillerLoopResult) -> MillerLoopResult {
+        &self * &rhs
     }
+}
+
+impl<'b> Mul<&'b MillerLoopResult> for &MillerLoopResult {
+    type Output = MillerLoopResult;
+    fn mul(self, rhs: &'b MillerLoopResult) -> MillerLoopResult {
+        MillerLoopResult(&self.0 * &rhs.0)
+    }
+}
+
+/// Walks the digits of the loop count below the leading one (T starts at
+/// Q), calling `step(0)` for each doubling and `step(±1)` for each
+/// addition of ±Q
+fn for_each_step(mut step: impl FnMut(i8)) {
+    for &digit in ATE_LOOP_NAF[..ATE_LOOP_NAF.len() - 1].iter().rev() {
+        step(0);
+        if digit != 0 {
+            step(digit);
+        }
+    }
+}
+
+/// π(Q) and -π²(Q), the points added in the two correction steps after
+/// the main loop
+fn frobenius_points(q: &(Fp2, Fp2)) -> [(Fp2, Fp2); 2] {
+    let q = G2Affine {
+        x: q.0.clone(),
+        y: q.1.clone(),
+        infinity: false,
+    };
+    let q1 = q.pi_map(1);
+    let q2 = q.pi_map(2);
+    [(q1.x, q1.y), (q2.x, -q2.y)]
+}
+
+/// Miller loop of the optimal Ate pairing:
+///
+///   f_{6u+2,Q}(P) · l_{[6u+2]Q,π(Q)}(P) · l_{[6u+2]Q+π(Q),-π²(Q)}(P)
+///
+/// The main loop runs over the NAF digits of 6u + 2, doubling T and adding
+/// ±Q; the two correction steps then add π(Q) and -π²(Q). Subtracting Q
+/// changes f by vertical lines, which lie in a proper subfield and vanish
+/// in the final exponentiation, so the pairing itself is unaffected.
+pub fn miller_loop(p: &G1, q: &G2) -> MillerLoopResult {
+    MillerLoopResult(multi_miller_loop(&[(p, q)]))
+}
+
+/// Miller loop using line coefficients precomputed by `G2::precompute`,
+/// leaving only the Fp12 work. Equal to `miller_loop(p, q)`.
+#[cfg(feature = "alloc")]
+pub fn miller_loop_precomputed(p: &G1, precomp: &G2Precomputed) -> MillerLoopResult {
+    multi_miller_loop_precomputed(&[(p, precomp)])
+}
+
+/// Product of the Miller loops of all pairs from precomputed G2 lines,
+/// sharing the squarings of f. Pairs with a point at infinity are skipped.
+#[cfg(feature = "alloc")]
+pub fn multi_miller_loop_precomputed(pairs: &[(&G1, &G2Precomputed)]) -> MillerLoopResult {
+    let mut terms: Vec<_> = pairs
+        .iter()
+        .filter(|(p, precomp)| !p.is_infinity() && !precomp.coeffs.is_empty())
+        .map(|(p, precomp)| (p.to_affine(), precomp.coeffs.iter()))
+        .collect();
 
     let mut f = Fp12::one();
-    let mut r = q.clone();
-    
-    // Get the binary representation of the loop count
-    let loop_count = ATE_LOOP_COUNT.clone();
-    let bits = loop_count.bits();
-    
-    // Miller's algorithm
-    for i in (0..bits).rev() {
-        // f = f² * l_{R,R}(P)
-        f = &f * &f;
-        let line = line_function(&r, &r, p);
-        f = &f * &line;
-        r = r.double();
-        
-        if loop_count.bit(i as u64) {
-            // f = f * l_{R,Q}(P)
-            let line = line_function(&r, q, p);
-            f = &f * &line;
-            r = r.add(q);
+    if terms.is_empty() {
+        return MillerLoopResult(f);
+    }
+
+    for_each_step(|digit| {
+        if digit == 0 {
+            f = f.square();
+        }
+        for (p, coeffs) in terms.iter_mut() {
+            let coeff = coeffs.next().expect("too few line coefficients");
+            f = ell(&f, coeff, p);
+        }
+    });
+    for (p, coeffs) in terms.iter_mut() {
+        for coeff in coeffs {
+            f = ell(&f, coeff, p);
         }
     }
-    
-    f
+
+    MillerLoopResult(f)
 }
 
 /// Final exponentiation step
-/// Raises the result to the power (p^12 - 1) / r
+/// Raises the result to the power (p^12 - 1) / r. `MillerLoopResult::final_exp`
+/// applies it and wraps the result as a `Gt`.
+///
+/// Strictly, the output is f^(m * (p^12 - 1) / r) with m = 2u(6u² + 3u + 1),
+/// see `Fp12::exp_hard_part`. Since m is coprime to r this is
+/// still a non-degenerate bilinear pairing.
 pub fn final_exponentiation(f: &Fp12) -> Fp12 {
     // Easy part: f^(p^6 - 1)(p^2 + 1)
-    // Hard part: f^((p^4 - p^2 + 1) / r)
-    
-    // For a simplified implementation, we just do a single exponentiation
-    // In practice, this should be optimized using the cyclotomic structure
-    
+    // The result lies in the cyclotomic subgroup
+
     // f^(p^6 - 1)
-    let f_p6 = f.frobenius_map(6);
+    let f_p6 = f.conjugate();
     let f_inv = f.inv();
     let f1 = &f_p6 * &f_inv;
-    
+
     // f1^(p^2 + 1)
     let f1_p2 = f1.frobenius_map(2);
     let f2 = &f1_p2 * &f1;
-    
-    // TODO: Implement the hard part of final exponentiation
-    // For now, return the result of the easy part
-    f2
+
+    f2.exp_hard_part()
 }
 
 /// Compute the optimal Ate pairing e(P, Q)
 /// P ∈ G1, Q ∈ G2
-/// Returns an element in Fp12
-pub fn pairing(p: &G1, q: &G2) -> Fp12 {
-    let f = miller_loop(p, q);
-    final_exponentiation(&f)
+/// Returns an element in GT
+///
+/// The result is the textbook pairing raised to m = 2u(6u² + 3u + 1), see
+/// `final_exponentiation`. Use `pairing_textbook` to compare GT values with
+/// other implementations.
+pub fn pairing(p: &G1, q: &G2) -> Gt {
+    miller_loop(p, q).final_exp()
+}
+
+/// e(P, Q) under the textbook final exponent (p¹² - 1)/r, the GT value
+/// py_ecc and other reference implementations return. Slower than
+/// `pairing` by one exponentiation in GT, see `Gt::to_textbook`.
+pub fn pairing_textbook(p: &G1, q: &G2) -> Gt {
+    pairing(p, q).to_textbook()
+}
+
+/// Compute the product of pairings ∏ e(Pᵢ, Qᵢ)
+///
+/// The Miller loops are interleaved so that f is squared once per step for
+/// all pairs, and the final exponentiation is only done once.
+pub fn multi_pairing(pairs: &[(&G1, &G2)]) -> Gt {
+    MillerLoopResult(multi_miller_loop(pairs)).final_exp()
+}
+
+/// Checks ∏ e(Pᵢ, Qᵢ) == 1, the usual form of pairing equations in
+/// signature and SNARK verifiers
+pub fn pairing_check(pairs: &[(&G1, &G2)]) -> bool {
+    multi_pairing(pairs).is_identity()
+}
+
+/// Product of the Miller loops of all pairs, computed in parallel.
+///
+/// The pairs are split into one chunk per rayon thread. Each chunk runs
+/// the interleaved loop of `multi_pairing` on its own, and the chunk
+/// results are multiplied together in order. Chunking keeps the shared
+/// squarings within a chunk, which one task per pair would lose. The final
+/// exponentiation of the product is left to the caller and stays
+/// sequential.
+#[cfg(feature = "rayon")]
+pub fn par_miller_loop(pairs: &[(&G1, &G2)]) -> MillerLoopResult {
+    use rayon::prelude::*;
+
+    let chunk_size = pairs.len().div_ceil(rayon::current_num_threads()).max(1);
+    let partials: Vec<Fp12> = pairs
+        .par_chunks(chunk_size)
+        .map(multi_miller_loop)
+        .collect();
+    MillerLoopResult(partials.iter().fold(Fp12::one(), |acc, f| &acc * f))
+}
+
+/// `miller_loop(p, q)` together with every line it multiplies in, each
+/// evaluated at P as a sparse Fp12, in loop order: one per doubling and
+/// addition step, then the two correction steps. f is squared before each
+/// doubling line, so replaying the lines that way gives back the result.
+/// With either point at infinity there are no lines and f is 1.
+#[cfg(feature = "debug_pairing")]
+pub fn miller_loop_debug(p: &G1, q: &G2) -> (Fp12, Vec<Fp12>) {
+    if p.is_infinity() {
+        return (Fp12::one(), Vec::new());
+    }
+    let p = p.to_affine();
+    let lines: Vec<Fp12> = G2Precomputed::new(q)
+        .coeffs
+        .iter()
+        .map(|coeff| ell(&Fp12::one(), coeff, &p))
+        .collect();
+    if lines.is_empty() {
+        return (Fp12::one(), lines);
+    }
+
+    let mut f = Fp12::one();
+    let mut next = lines.iter();
+    for_each_step(|digit| {
+        if digit == 0 {
+            f = f.square();
+        }
+        f = &f * next.next().expect("too few lines");
+    });
+    for line in next {
+        f = &f * line;
+    }
+    (f, lines)
+}
+
+/// Product of the Miller loops of all pairs, sharing the squarings of f.
+/// Pairs with a point at infinity contribute 1 and are skipped.
+#[cfg(feature = "alloc")]
+fn multi_miller_loop(pairs: &[(&G1, &G2)]) -> Fp12 {
+    let mut terms: Vec<_> = pairs
+        .iter()
+        .filter_map(|(p, q)| miller_term(p, q))
+        .collect();
+    interleaved_miller_loop(&mut terms)
+}
+
+/// Product of the Miller loops of all pairs. Without an allocator there is
+/// nowhere to keep the state of every pair, so the loops run one after the
+/// other and do not share the squarings of f.
+#[cfg(not(feature = "alloc"))]
+fn multi_miller_loop(pairs: &[(&G1, &G2)]) -> Fp12 {
+    pairs
+        .iter()
+        .filter_map(|(p, q)| miller_term(p, q))
+        .fold(Fp12::one(), |f, term| {
+            &f * &interleaved_miller_loop(&mut [term])
+        })
+}
+
+/// The state of one pair in the Miller loop: the affine P, the running
+/// point T and the affine Q and -Q
+type MillerTerm = ((Fp, Fp), MillerPoint, (Fp2, Fp2), (Fp2, Fp2));
+
+/// The initial state of the Miller loop of a pair, or `None` when either
+/// point is at infinity and the pair contributes 1
+fn miller_term(p: &G1, q: &G2) -> Option<MillerTerm> {
+    if p.is_infinity() || q.is_infinity() {
+        return None;
+    }
+    let q = q.to_affine();
+    let neg_q = (q.0.clone(), -q.1.clone());
+    Some((p.to_affine(), MillerPoint::new(&q), q, neg_q))
+}
+
+/// Runs the Miller loops of all terms together, squaring f once per step
+fn interleaved_miller_loop(terms: &mut [MillerTerm]) -> Fp12 {
+    let mut f = Fp12::one();
+    if terms.is_empty() {
+        return f;
+    }
+
+    for_each_step(|digit| {
+        if digit == 0 {
+            f = f.square();
+        }
+        for (p, r, q, neg_q) in terms.iter_mut() {
+            let coeff = match digit {
+                0 => r.double_step(),
+                1 => r.add_step(q),
+                _ => r.add_step(neg_q),
+            };
+            f = ell(&f, &coeff, p);
+        }
+    });
+    for (p, r, q, _) in terms.iter_mut() {
+        for q in frobenius_points(q) {
+            f = ell(&f, &r.add_step(&q), p);
+        }
+    }
+
+    f
 }
 
 /// Check pairing bilinearity: e(aP, bQ) = e(P, Q)^(ab)
 pub fn check_bilinearity(p: &G1, q: &G2, a: u128, b: u128) -> bool {
     let ap = p.mul_u128(a);
-    let bq = q.mul_scalar(&BigUint::from(b));
+    let bq = q.mul_limbs(&[b as u64, (b >> 64) as u64, 0, 0]);
     let e_ab = pairing(&ap, &bq);
-    
+
     let e_pq = pairing(p, q);
-    let ab = a as u128 * b as u128;
-    let e_pq_ab = e_pq.pow(&BigUint::from(ab));
-    
+    let ab = a * b;
+    let e_pq_ab = e_pq.pow_vartime(&[ab as u64, (ab >> 64) as u64]);
+
     e_ab == e_pq_ab
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fp::Fp;
+    use crate::fp::{Fp, P};
     use crate::fp2::Fp2;
+    use crate::fp6::Fp6;
+    use crate::GROUP_ORDER;
+    use num_bigint::BigUint;
+    use num_traits::One;
 
     #[test]
     fn test_pairing_identity() {
         // e(O, Q) = 1
         let inf_g1 = G1::infinity();
-        let q = G2 {
-            x: Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
-            y: Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
-            z: Fp2::one(),
-        };
+        let q = G2::generator();
         let result = pairing(&inf_g1, &q);
-        assert_eq!(result, Fp12::one());
+        assert_eq!(result, Gt::one());
     }
 
     #[test]
     fn test_pairing_non_degenerate() {
-        let p = G1 {
-            x: Fp::new(1u32.into()),
-            y: Fp::new(2u32.into()),
-            z: Fp::one(),
-        };
-        let q = G2 {
-            x: Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
-            y: Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
-            z: Fp2::one(),
-        };
-        
-        let result = pairing(&p, &q);
+        let p = G1::from_affine(Fp::new(1u32.into()), Fp::new(2u32.into())).unwrap();
+        let q = G2::generator();
+
         // The result should not be the identity
-        // Note: This is a weak test since we haven't verified the curve points
-        // In a real implementation, use known generator points
-        assert!(result == Fp12::one() || result != Fp12::one());
+        let result = pairing(&p, &q);
+        assert!(result != Gt::one());
+    }
+
+    /// Checks that `coeff` describes the line through T with the given
+    /// affine slope, up to an Fp2 factor, and that the point matches `expected`
+    fn check_step(r: &MillerPoint, coeff: &LineCoeff, t: &(Fp2, Fp2), slope: &Fp2, expected: &G2) {
+        let z_inv = r.z.inv();
+        assert_eq!((&r.x * &z_inv, &r.y * &z_inv), expected.to_affine());
+
+        // l = 1 - slope*w + (slope*x_T - y_T)*vw, before scaling by P
+        let affine = (Fp2::one(), -slope.clone(), &(slope * &t.0) - &t.1);
+        let scale = &coeff.ell_0 * &affine.0.inv();
+        assert_eq!(coeff.ell_w, &affine.1 * &scale);
+        assert_eq!(coeff.ell_vw, &affine.2 * &scale);
     }
 
     #[test]
-    #[ignore] // This test might be slow
-    fn test_bilinearity() {
-        let p = G1 {
-            x: Fp::new(1u32.into()),
-            y: Fp::new(2u32.into()),
-            z: Fp::one(),
+    fn test_line_steps() {
+        let q = G2::generator();
+        let t = q.mul_scalar(&BigUint::from(5u32));
+        let (tx, ty) = t.to_affine();
+        let (qx, qy) = q.to_affine();
+
+        // Doubling: tangent slope 3x²/2y
+        let mut r = MillerPoint {
+            x: &tx * &Fp2::new(Fp::new(7u32.into()), Fp::one()),
+            y: &ty * &Fp2::new(Fp::new(7u32.into()), Fp::one()),
+            z: Fp2::new(Fp::new(7u32.into()), Fp::one()),
         };
-        let q = G2 {
-            x: Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
-            y: Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
+        let coeff = r.double_step();
+        let three_x2 = &(&tx * &tx) * &Fp2::new(Fp::new(3u32.into()), Fp::zero());
+        let slope = &three_x2 * &(&ty + &ty).inv();
+        check_step(&r, &coeff, &(tx.clone(), ty.clone()), &slope, &t.double());
+
+        // Addition: chord slope (y_Q - y_T) / (x_Q - x_T)
+        let mut r = MillerPoint {
+            x: tx.clone(),
+            y: ty.clone(),
             z: Fp2::one(),
         };
-        
+        let coeff = r.add_step(&(qx.clone(), qy.clone()));
+        let slope = &(&qy - &ty) * &(&qx - &tx).inv();
+        check_step(&r, &coeff, &(tx, ty), &slope, &t.add(&q));
+    }
+
+    #[test]
+    fn test_miller_loop_precomputed() {
+        let p = G1::generator().mul_u128(7);
+        let q = G2::generator().mul_scalar(&BigUint::from(11u32));
+        let precomp = q.precompute();
+        assert_eq!(
+            miller_loop_precomputed(&p, &precomp).inner(),
+            miller_loop(&p, &q).inner()
+        );
+
+        let p2 = G1::generator();
+        let q2 = G2::generator();
+        assert_eq!(
+            multi_miller_loop_precomputed(&[(&p, &precomp), (&p2, &q2.precompute())]).inner(),
+            &multi_miller_loop(&[(&p, &q), (&p2, &q2)])
+        );
+
+        assert!(G2::infinity().precompute().coeffs.is_empty());
+        assert_eq!(
+            miller_loop_precomputed(&p, &G2::infinity().precompute()).inner(),
+            &Fp12::one()
+        );
+    }
+
+    #[test]
+    fn test_miller_loop_result() {
+        let (p, q) = (G1::generator(), G2::generator());
+        let p2 = p.mul_u128(3);
+        let f = miller_loop(&p, &q);
+        let f2 = miller_loop(&p2, &q);
+
+        // Equal pairings, different Miller loop values
+        assert_ne!(f2.inner(), (&f * &f * f.clone()).inner());
+        assert_eq!(
+            f2.clone().final_exp(),
+            pairing(&p, &q).pow(&BigUint::from(3u32))
+        );
+
+        assert_eq!(
+            (&f * &f2).final_exp(),
+            multi_pairing(&[(&p, &q), (&p2, &q)])
+        );
+        assert!(MillerLoopResult::one().final_exp().is_identity());
+        assert_eq!((f.clone() * MillerLoopResult::default()).inner(), f.inner());
+    }
+
+    #[test]
+    fn test_multi_pairing() {
+        let p = G1::generator().mul_u128(5);
+        let q = G2::generator().mul_scalar(&BigUint::from(3u32));
+        let neg_p = -p;
+
+        assert_eq!(multi_pairing(&[(&p, &q)]), pairing(&p, &q));
+        assert_eq!(
+            multi_pairing(&[(&p, &q), (&G1::generator(), &G2::generator())]),
+            &pairing(&p, &q) * &pairing(&G1::generator(), &G2::generator())
+        );
+        assert!(pairing_check(&[(&p, &q), (&neg_p, &q)]));
+        assert!(pairing_check(&[]));
+        assert!(pairing_check(&[(&G1::infinity(), &q)]));
+    }
+
+    #[test]
+    fn test_final_exponentiation_matches_exponent() {
+        let f = Fp12::new(
+            Fp6::new(
+                Fp2::new(Fp::new(1u32.into()), Fp::new(2u32.into())),
+                Fp2::new(Fp::new(3u32.into()), Fp::new(4u32.into())),
+                Fp2::new(Fp::new(5u32.into()), Fp::new(6u32.into())),
+            ),
+            Fp6::new(
+                Fp2::new(Fp::new(7u32.into()), Fp::new(8u32.into())),
+                Fp2::new(Fp::new(9u32.into()), Fp::new(10u32.into())),
+                Fp2::new(Fp::new(11u32.into()), Fp::new(12u32.into())),
+            ),
+        );
+
+        // The hard part computes the exponent multiplied by 2u(6u² + 3u + 1)
+        let u = &BigUint::from(crate::BN_PARAM);
+        let m = BigUint::from(2u32)
+            * u
+            * (BigUint::from(6u32) * u * u + BigUint::from(3u32) * u + BigUint::one());
+        let final_exp = (P.pow(12) - BigUint::one()) / &*GROUP_ORDER;
+        let expected = f.pow(&(final_exp * m));
+        assert_eq!(final_exponentiation(&f), expected);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_miller_loop() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        let points: Vec<(G1, G2)> = (0..8)
+            .map(|_| {
+                (
+                    G1::generator().mul_u128(rng.gen()),
+                    G2::generator().mul_scalar(&BigUint::from(rng.gen::<u128>())),
+                )
+            })
+            .collect();
+        let pairs: Vec<(&G1, &G2)> = points.iter().map(|(p, q)| (p, q)).collect();
+
+        assert_eq!(par_miller_loop(&pairs).inner(), &multi_miller_loop(&pairs));
+        assert_eq!(
+            par_miller_loop(&pairs[..1]).inner(),
+            miller_loop(pairs[0].0, pairs[0].1).inner()
+        );
+        assert_eq!(par_miller_loop(&[]).inner(), &Fp12::one());
+    }
+
+    #[cfg(feature = "debug_pairing")]
+    #[test]
+    fn test_miller_loop_debug() {
+        let p = G1::generator().mul_u128(7);
+        let q = G2::generator().double();
+        let (f, lines) = miller_loop_debug(&p, &q);
+        assert_eq!(&f, miller_loop(&p, &q).inner());
+
+        // 65 doublings, 21 additions of ±Q and the two correction steps
+        let doublings = ATE_LOOP_NAF.len() - 1;
+        let additions = ATE_LOOP_NAF[..doublings]
+            .iter()
+            .filter(|&&d| d != 0)
+            .count();
+        assert_eq!(lines.len(), doublings + additions + 2);
+        assert!(lines.iter().all(|l| *l != Fp12::one() && !l.is_zero()));
+
+        // The running value after each step is the previous one, squared on
+        // doublings, times that step's line
+        let mut expected = Fp12::one();
+        let mut i = 0;
+        for_each_step(|digit| {
+            if digit == 0 {
+                expected = &expected * &expected;
+            }
+            expected = &expected * &lines[i];
+            i += 1;
+        });
+        assert_eq!(&(&expected * &lines[i]) * &lines[i + 1], f);
+
+        assert_eq!(
+            miller_loop_debug(&G1::infinity(), &q),
+            (Fp12::one(), Vec::new())
+        );
+        assert_eq!(miller_loop_debug(&p, &G2::infinity()).1.len(), 0);
+    }
+
+    #[test]
+    fn test_ate_loop_naf() {
+        let value = ATE_LOOP_NAF
+            .iter()
+            .rev()
+            .fold(0i128, |acc, &d| 2 * acc + d as i128);
+        assert_eq!(value, 6 * crate::BN_PARAM as i128 + 2);
+        assert_eq!(ATE_LOOP_NAF[ATE_LOOP_NAF.len() - 1], 1);
+        assert_eq!(ATE_LOOP_NAF.iter().filter(|&&d| d != 0).count(), 22);
+    }
+
+    #[test]
+    fn test_bilinearity() {
+        let p = G1::generator();
+        let q = G2::generator();
+
         // Test with small scalars
         assert!(check_bilinearity(&p, &q, 2, 3));
+
+        // e([a]P, Q) == e(P, [a]Q), with a scalar that exercises every
+        // line of the loop
+        let a = BigUint::parse_bytes(b"123456789abcdef0123456789abcdef", 16).unwrap();
+        let e = pairing(&p, &q);
+        assert_eq!(
+            pairing(&p.mul_scalar(&a), &q),
+            pairing(&p, &q.mul_scalar(&a))
+        );
+        assert_eq!(pairing(&p.mul_scalar(&a), &q), e.pow(&a));
+
+        // Non-degenerate, of order r
+        assert!(!e.is_identity());
+        assert!(e.pow(&crate::GROUP_ORDER).is_identity());
     }
 }
@@ -0,0 +1,70 @@
+use crate::g1::G1;
+use num_bigint::BigUint;
+
+/// Pedersen commitments C = [v]G + [r]H in G1.
+///
+/// Hiding is perfect as long as r is uniform mod the group order. Binding
+/// rests on the discrete logarithm assumption in G1: anyone who knows
+/// log_G(H) can open a commitment to any value. H must therefore be chosen
+/// so that nobody knows that logarithm, for example as `hash_to_g1` of a
+/// public seed, and never as a known multiple of G.
+#[derive(Clone, Debug)]
+pub struct Parameters {
+    pub g: G1,
+    pub h: G1,
+}
+
+impl Parameters {
+    /// Parameters from two independent G1 generators, see the type docs
+    /// for how to choose `h`
+    pub fn setup(g: G1, h: G1) -> Self {
+        Self { g, h }
+    }
+
+    /// Commitment [value]G + [randomness]H. Both are secret while the
+    /// commitment is unopened, so both go through the `mul_scalar` ladder.
+    pub fn commit(&self, value: &BigUint, randomness: &BigUint) -> G1 {
+        self.g.mul_scalar(value).add(&self.h.mul_scalar(randomness))
+    }
+
+    /// Checks that (value, randomness) opens `commitment`
+    pub fn verify_opening(&self, commitment: &G1, value: &BigUint, randomness: &BigUint) -> bool {
+        commitment.is_on_curve()
+            && self.commit(value, randomness).to_affine() == commitment.to_affine()
+    }
+
+    /// Sum of two commitments, which commits to the sum of the values under
+    /// the sum of the randomness
+    pub fn add_commitments(c1: &G1, c2: &G1) -> G1 {
+        c1.add(c2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::g1::hash_to_g1;
+
+    fn params() -> Parameters {
+        Parameters::setup(
+            G1::generator(),
+            hash_to_g1(b"pedersen h", b"BN254-PEDERSEN-TEST"),
+        )
+    }
+
+    #[test]
+    fn test_commit_and_open() {
+        let params = params();
+        let (v1, r1) = (BigUint::from(42u32), BigUint::from(987654321u64));
+        let (v2, r2) = (BigUint::from(58u32), BigUint::from(123456789u64));
+
+        let c1 = params.commit(&v1, &r1);
+        let c2 = params.commit(&v2, &r2);
+        assert!(params.verify_opening(&c1, &v1, &r1));
+        assert!(!params.verify_opening(&c1, &v2, &r1));
+        assert!(!params.verify_opening(&c1, &v1, &r2));
+
+        let sum = Parameters::add_commitments(&c1, &c2);
+        assert!(params.verify_opening(&sum, &(&v1 + &v2), &(&r1 + &r2)));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::error::Error;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::GROUP_ORDER;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// An element of the scalar field Fr, i.e. an integer modulo the group
+/// order r, always kept reduced to [0, r).
+///
+/// Scalar multiplication also accepts plain `BigUint`s, which are used as
+/// they are; a `Scalar` rules out passing an unreduced value by mistake.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scalar(BigUint);
+
+impl Scalar {
+    pub fn zero() -> Self {
+        Scalar(BigUint::zero())
+    }
+
+    pub fn one() -> Self {
+        Scalar(BigUint::from(1u32))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// The reduced value
+    pub fn as_biguint(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// A uniformly random scalar, see `Fp::random`
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        Scalar(crate::g1::random_scalar(rng))
+    }
+
+    /// Decodes 32 big-endian bytes. Values not below r are rejected rather
+    /// than reduced, so every scalar has exactly one encoding.
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let n = BigUint::from_bytes_be(bytes);
+        if n >= *GROUP_ORDER {
+            return Err(Error::ScalarOutOfRange);
+        }
+        Ok(Scalar(n))
+    }
+
+    /// The value as 32 bytes big-endian
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let bytes = self.0.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
+    }
+
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.inv())
+    }
+
+    /// The inverse by Fermat's little theorem, x^(r-2). Panics if `self`
+    /// is zero; `try_inv` returns an error instead.
+    pub fn inv(&self) -> Self {
+        assert!(!self.is_zero(), "Scalar::inv: zero has no inverse");
+        let r = &*GROUP_ORDER;
+        Scalar(self.0.modpow(&(r - 2u32), r))
+    }
+}
+
+/// Reduces modulo r
+impl From<BigUint> for Scalar {
+    fn from(n: BigUint) -> Self {
+        Scalar(n % &*GROUP_ORDER)
+    }
+}
+
+/// Every u64 is below r
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Self {
+        Scalar(BigUint::from(n))
+    }
+}
+
+/// Every u128 is below r
+impl From<u128> for Scalar {
+    fn from(n: u128) -> Self {
+        Scalar(BigUint::from(n))
+    }
+}
+
+impl From<Scalar> for BigUint {
+    fn from(s: Scalar) -> Self {
+        s.0
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Add for &Scalar {
+    type Output = Scalar;
+    fn add(self, rhs: &Scalar) -> Scalar {
+        let sum = &self.0 + &rhs.0;
+        if sum >= *GROUP_ORDER {
+            Scalar(sum - &*GROUP_ORDER)
+        } else {
+            Scalar(sum)
+        }
+    }
+}
+
+impl Sub for &Scalar {
+    type Output = Scalar;
+    fn sub(self, rhs: &Scalar) -> Scalar {
+        if self.0 >= rhs.0 {
+            Scalar(&self.0 - &rhs.0)
+        } else {
+            Scalar(&*GROUP_ORDER - &rhs.0 + &self.0)
+        }
+    }
+}
+
+impl Mul for &Scalar {
+    type Output = Scalar;
+    fn mul(self, rhs: &Scalar) -> Scalar {
+        Scalar((&self.0 * &rhs.0) % &*GROUP_ORDER)
+    }
+}
+
+impl Neg for &Scalar {
+    type Output = Scalar;
+    fn neg(self) -> Scalar {
+        if self.is_zero() {
+            Scalar::zero()
+        } else {
+            Scalar(&*GROUP_ORDER - &self.0)
+        }
+    }
+}
+
+/// Scalar multiplication, delegating to `G1::mul_scalar`
+impl Mul<&Scalar> for &G1 {
+    type Output = G1;
+    fn mul(self, rhs: &Scalar) -> G1 {
+        self.mul_scalar(&rhs.0)
+    }
+}
+
+/// Scalar multiplication, delegating to `G2::mul_scalar`
+impl Mul<&Scalar> for &G2 {
+    type Output = G2;
+    fn mul(self, rhs: &Scalar) -> G2 {
+        self.mul_scalar(&rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::from(rng.gen::<u128>());
+        let b = Scalar::from(rng.gen::<u128>());
+        let minus_one = -&Scalar::one();
+        assert_eq!(minus_one.as_biguint(), &(&*GROUP_ORDER - 1u32));
+
+        assert_eq!(&(&a + &b) - &b, a);
+        assert_eq!(&a - &a, Scalar::zero());
+        assert_eq!(&a + &(-&a), Scalar::zero());
+        assert_eq!(&minus_one + &Scalar::one(), Scalar::zero());
+        assert_eq!(&a * &a.inv(), Scalar::one());
+        assert_eq!(Scalar::zero().try_inv(), Err(Error::ZeroInverse));
+
+        // From<BigUint> reduces
+        let big = &*GROUP_ORDER + BigUint::from(5u32);
+        assert_eq!(Scalar::from(big), Scalar::from(5u64));
+    }
+
+    #[test]
+    fn test_bytes() {
+        let a = Scalar::from(0x0123_4567_89ab_cdefu64);
+        let bytes = a.to_bytes_be();
+        assert_eq!(bytes[24..], 0x0123_4567_89ab_cdefu64.to_be_bytes());
+        assert_eq!(Scalar::from_bytes_be(&bytes), Ok(a));
+
+        let minus_one = -&Scalar::one();
+        assert_eq!(
+            Scalar::from_bytes_be(&minus_one.to_bytes_be()),
+            Ok(minus_one)
+        );
+        let mut r = [0u8; 32];
+        let r_bytes = GROUP_ORDER.to_bytes_be();
+        r[32 - r_bytes.len()..].copy_from_slice(&r_bytes);
+        assert_eq!(Scalar::from_bytes_be(&r), Err(Error::ScalarOutOfRange));
+    }
+
+    #[test]
+    fn test_point_mul() {
+        let a = Scalar::from(12345u64);
+        let b = -&Scalar::from(6789u64);
+        let g1 = G1::generator();
+        let g2 = G2::generator();
+
+        // [a]P + [b]P = [a + b]P, with b wrapping around r
+        let lhs = (&g1 * &a).add(&(&g1 * &b));
+        assert_eq!(lhs.to_affine(), (&g1 * &(&a + &b)).to_affine());
+        let lhs = (&g2 * &a).add(&(&g2 * &b));
+        assert_eq!(lhs.to_affine(), (&g2 * &(&a + &b)).to_affine());
+    }
+}
@@ -0,0 +1,136 @@
+//! The two-adic structure of the scalar field Fr and a number-theoretic
+//! transform over it. r - 1 = 2²⁸·t with t odd, so Fr has roots of unity
+//! of every order up to 2²⁸ and polynomials of up to 2²⁸ coefficients can
+//! be evaluated on a multiplicative subgroup with an FFT.
+
+use crate::scalar::Scalar;
+use num_bigint::BigUint;
+
+/// The largest k such that 2^k divides r - 1
+pub const TWO_ADICITY: u32 = 28;
+
+lazy_static::lazy_static! {
+    /// A primitive 2²⁸-th root of unity in Fr, 5^t for the generator 5 of
+    /// Fr* and the odd part t of r - 1
+    pub static ref TWO_ADIC_ROOT_OF_UNITY: Scalar = Scalar::from(
+        BigUint::parse_bytes(
+            b"19103219067921713944291392827692070036145651957329286315305642004821462161904",
+            10
+        )
+        .unwrap()
+    );
+}
+
+/// A primitive 2^log_n-th root of unity, obtained by squaring
+/// `TWO_ADIC_ROOT_OF_UNITY` 28 - log_n times
+pub fn root_of_unity(log_n: u32) -> Scalar {
+    assert!(
+        log_n <= TWO_ADICITY,
+        "root_of_unity: Fr has no root of unity of order 2^{log_n}"
+    );
+    let mut omega = TWO_ADIC_ROOT_OF_UNITY.clone();
+    for _ in log_n..TWO_ADICITY {
+        omega = &omega * &omega;
+    }
+    omega
+}
+
+/// Replaces the coefficients a₀, ..., aₙ₋₁ (lowest degree first) with the
+/// evaluations f(ωⁱ) of their polynomial at the n-th roots of unity, where
+/// ω = `root_of_unity(log2 n)`. This is an in-place radix-2 Cooley-Tukey
+/// transform: a bit-reversal permutation followed by log2 n butterfly
+/// layers, O(n log n) multiplications in all. The length must be a power
+/// of two no larger than 2²⁸.
+pub fn ntt(values: &mut [Scalar]) {
+    let n = values.len();
+    assert!(
+        n.is_power_of_two() && n.trailing_zeros() <= TWO_ADICITY,
+        "ntt: length must be a power of two up to 2^28"
+    );
+    let log_n = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = bit_reverse(i, log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    for s in 1..=log_n {
+        let half = 1 << (s - 1);
+        let omega = root_of_unity(s);
+        for start in (0..n).step_by(2 * half) {
+            let mut w = Scalar::one();
+            for k in start..start + half {
+                let t = &w * &values[k + half];
+                values[k + half] = &values[k] - &t;
+                values[k] = &values[k] + &t;
+                w = &w * &omega;
+            }
+        }
+    }
+}
+
+/// The low `bits` bits of i in reverse order
+fn bit_reverse(i: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        i.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GROUP_ORDER;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let r_minus_one = &*GROUP_ORDER - 1u32;
+        assert!((&r_minus_one >> TWO_ADICITY).bit(0));
+        assert!(!(&r_minus_one >> (TWO_ADICITY - 1)).bit(0));
+
+        // ω^(2^27) = -1, so ω has order exactly 2^28
+        let omega = root_of_unity(TWO_ADICITY);
+        assert_eq!(omega, *TWO_ADIC_ROOT_OF_UNITY);
+        let half_order = BigUint::from(1u32) << (TWO_ADICITY - 1);
+        assert_eq!(
+            omega.as_biguint().modpow(&half_order, &GROUP_ORDER),
+            r_minus_one
+        );
+        assert_eq!(root_of_unity(1), -&Scalar::one());
+        assert_eq!(root_of_unity(0), Scalar::one());
+    }
+
+    #[test]
+    fn test_ntt() {
+        // Against direct evaluation at the powers of ω
+        for log_n in 0..=4 {
+            let n = 1usize << log_n;
+            let coeffs: Vec<Scalar> = (0..n as u64).map(|i| Scalar::from(3 * i + 7)).collect();
+            let omega = root_of_unity(log_n);
+            let mut x = Scalar::one();
+            let mut expected = Vec::with_capacity(n);
+            for _ in 0..n {
+                let mut eval = Scalar::zero();
+                for c in coeffs.iter().rev() {
+                    eval = &(&eval * &x) + c;
+                }
+                expected.push(eval);
+                x = &x * &omega;
+            }
+
+            let mut values = coeffs;
+            ntt(&mut values);
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn test_ntt_length() {
+        ntt(&mut [Scalar::one(), Scalar::one(), Scalar::one()]);
+    }
+}
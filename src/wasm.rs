@@ -0,0 +1,161 @@
+//! JavaScript bindings through wasm-bindgen, enabled by the `wasm` feature.
+//! The crate is an rlib only, since a cdylib would need its own panic
+//! handler and allocator in `no_std` builds, so the wasm module is built
+//! with an explicit crate type and then passed to the wasm-bindgen CLI:
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target nodejs --out-dir pkg target/wasm32-unknown-unknown/release/bn254.wasm
+//! ```
+//!
+//! `pkg/` then holds the JavaScript glue and the TypeScript declarations
+//! `pkg/bn254.d.ts`, and `node js/smoke.mjs` exercises them.
+//! `scripts/wasm-smoke.sh` runs all three steps, which CI does on every
+//! push.
+//!
+//! Points use the Ethereum byte ABI: G1 as the 64-byte EIP-196 x || y and
+//! G2 as the 128-byte EIP-197 x.c1 || x.c0 || y.c1 || y.c0, every value
+//! 32 bytes big-endian and infinity as all zeros. Errors surface in
+//! JavaScript as thrown `Error`s carrying the `Error` message.
+
+use crate::eip197::ec_pairing;
+use crate::error::Error;
+use crate::fp12::Fp12;
+use crate::g1::{DecodeError, G1Affine, G1};
+use crate::g2::{G2Affine, G2};
+use crate::pairing::pairing_textbook;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use wasm_bindgen::prelude::*;
+
+/// e(P, Q) as 384 bytes: the twelve Fp coefficients of the GT element,
+/// c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1 for Fp12 = Fp6[w]/(w² - v),
+/// Fp6 = Fp2[v]/(v³ - (u + 9)) and Fp2 = Fp[u]/(u² + 1), each 32 bytes
+/// big-endian. The value is the textbook pairing under the final exponent
+/// (p¹² - 1)/r, the same as py_ecc's, not the m-th power that
+/// `pairing::pairing` returns.
+#[wasm_bindgen]
+pub fn wasm_pairing(g1_bytes: &[u8], g2_bytes: &[u8]) -> Result<Vec<u8>, JsError> {
+    pairing_bytes(g1_bytes, g2_bytes).map_err(js_error)
+}
+
+/// Whether ∏ e(Pᵢ, Qᵢ) == 1 for the concatenated 192-byte (G1, G2) pairs,
+/// as in the `ecPairing` precompile. This is the check a Groth16 verifier
+/// performs.
+#[wasm_bindgen]
+pub fn wasm_pairing_check(pairs_bytes: &[u8]) -> Result<bool, JsError> {
+    pairing_check_bytes(pairs_bytes).map_err(js_error)
+}
+
+/// [k]P for a 64-byte G1 point and a 32-byte big-endian scalar, returning
+/// 64 bytes. The scalar is not required to be below r.
+#[wasm_bindgen]
+pub fn wasm_g1_mul(point_bytes: &[u8], scalar_bytes: &[u8]) -> Result<Vec<u8>, JsError> {
+    g1_mul_bytes(point_bytes, scalar_bytes).map_err(js_error)
+}
+
+fn js_error(e: Error) -> JsError {
+    JsError::new(&alloc::string::ToString::to_string(&e))
+}
+
+fn pairing_bytes(g1_bytes: &[u8], g2_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let p = G1Affine::from_uncompressed(
+        g1_bytes
+            .try_into()
+            .map_err(|_| DecodeError::InvalidLength)?,
+    )?;
+    let q = G2Affine::from_uncompressed(
+        g2_bytes
+            .try_into()
+            .map_err(|_| DecodeError::InvalidLength)?,
+    )?;
+    Ok(fp12_to_bytes(
+        pairing_textbook(&G1::from(p), &G2::from(q)).inner(),
+    ))
+}
+
+fn pairing_check_bytes(pairs_bytes: &[u8]) -> Result<bool, Error> {
+    Ok(ec_pairing(pairs_bytes)?[31] == 1)
+}
+
+fn g1_mul_bytes(point_bytes: &[u8], scalar_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let p = G1Affine::from_uncompressed(
+        point_bytes
+            .try_into()
+            .map_err(|_| DecodeError::InvalidLength)?,
+    )?;
+    if scalar_bytes.len() != 32 {
+        return Err(DecodeError::InvalidLength.into());
+    }
+    let k = BigUint::from_bytes_be(scalar_bytes);
+    let res = G1::from(p).mul_scalar(&k);
+    Ok(G1Affine::from(res).to_uncompressed().to_vec())
+}
+
+/// The twelve Fp coefficients of c0 + c1·w, with each Fp6 as
+/// c0 + c1·v + c2·v² and each Fp2 as c0 + c1·u, in that nesting order
+/// (c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1), 32 bytes big-endian each
+fn fp12_to_bytes(f: &Fp12) -> Vec<u8> {
+    let mut out = Vec::with_capacity(384);
+    for fp6 in [&f.c0, &f.c1] {
+        for fp2 in [&fp6.c0, &fp6.c1, &fp6.c2] {
+            out.extend_from_slice(&fp2.c0.to_bytes_be());
+            out.extend_from_slice(&fp2.c1.to_bytes_be());
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn g1_bytes(p: &G1) -> Vec<u8> {
+        G1Affine::from(*p).to_uncompressed().to_vec()
+    }
+
+    fn g2_bytes(q: &G2) -> Vec<u8> {
+        G2Affine::from(q.clone()).to_uncompressed().to_vec()
+    }
+
+    #[test]
+    fn test_bindings() {
+        let g1 = G1::generator();
+        let g2 = G2::generator();
+
+        let e = pairing_bytes(&g1_bytes(&g1), &g2_bytes(&g2)).unwrap();
+        assert_eq!(e.len(), 384);
+        assert_eq!(e, fp12_to_bytes(pairing_textbook(&g1, &g2).inner()));
+        // c0.c0.c0 of py_ecc's e(G1, G2), see tests/vectors.rs
+        assert_eq!(
+            e[..32],
+            [
+                0x12, 0xc7, 0x0e, 0x90, 0xe1, 0x2b, 0x78, 0x74, 0x51, 0x0c, 0xd1, 0x70, 0x7e, 0x88,
+                0x56, 0xf7, 0x1b, 0xf7, 0xf6, 0x1d, 0x72, 0x63, 0x1e, 0x26, 0x8f, 0xca, 0x81, 0x00,
+                0x0d, 0xb9, 0xa1, 0xf5,
+            ]
+        );
+        assert_eq!(
+            pairing_bytes(&g1_bytes(&g1)[..63], &g2_bytes(&g2)),
+            Err(Error::InvalidEncoding)
+        );
+
+        let mut pairs = g1_bytes(&g1);
+        pairs.extend(g2_bytes(&g2));
+        pairs.extend(g1_bytes(&-g1));
+        pairs.extend(g2_bytes(&g2));
+        assert_eq!(pairing_check_bytes(&pairs), Ok(true));
+        assert_eq!(pairing_check_bytes(&pairs[..192]), Ok(false));
+        assert_eq!(
+            pairing_check_bytes(&pairs[..100]),
+            Err(Error::InvalidEncoding)
+        );
+
+        let mut two = [0u8; 32];
+        two[31] = 2;
+        assert_eq!(
+            g1_mul_bytes(&g1_bytes(&g1), &two),
+            Ok(g1_bytes(&g1.double()))
+        );
+    }
+}
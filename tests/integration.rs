@@ -10,16 +10,16 @@ fn test_fp_field_laws() {
     let c = fp::Fp::new(31u32.into());
 
     // Associativity: (a + b) + c = a + (b + c)
-    let left = (a.clone() + b.clone()) + c.clone();
-    let right = a.clone() + (b.clone() + c.clone());
+    let left = (a + b) + c;
+    let right = a + (b + c);
     assert_eq!(left, right);
 
     // Commutativity: a + b = b + a
-    assert_eq!(a.clone() + b.clone(), b.clone() + a.clone());
+    assert_eq!(a + b, b + a);
 
     // Distributivity: a * (b + c) = a * b + a * c
-    let left = a.clone() * (b.clone() + c.clone());
-    let right = a.clone() * b.clone() + a.clone() * c.clone();
+    let left = a * (b + c);
+    let right = a * b + a * c;
     assert_eq!(left, right);
 }
 
@@ -47,37 +47,35 @@ fn test_fp2_field_laws() {
 #[test]
 fn test_g1_curve_equation() {
     // Test that known points satisfy y² = x³ + 3
-    let p = g1::G1 {
-        x: fp::Fp::new(1u32.into()),
-        y: fp::Fp::new(2u32.into()),
-        z: fp::Fp::one(),
-    };
+    let p = g1::G1::from_affine(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())).unwrap();
     assert!(p.is_on_curve());
+    // 6² = 36 ≠ 3³ + 3
+    assert_eq!(
+        g1::G1::from_affine(fp::Fp::new(3u32.into()), fp::Fp::new(6u32.into())),
+        Err(g1::PointError::NotOnCurve)
+    );
+    let off_curve =
+        g1::G1::from_affine_unchecked(fp::Fp::new(3u32.into()), fp::Fp::new(6u32.into()));
+    assert!(!off_curve.is_on_curve());
+
+    // The standard generators must satisfy their curve equations
+    assert!(g1::G1::generator().is_on_curve());
+    assert!(g2::G2::generator().is_on_curve());
 
-    // Test generator point (if we had the actual generator)
-    // For now, test that infinity is on the curve
     let inf = g1::G1::infinity();
     assert!(inf.is_on_curve());
 }
 
 #[test]
 fn test_g1_group_laws() {
-    let p = g1::G1 {
-        x: fp::Fp::new(3u32.into()),
-        y: fp::Fp::new(6u32.into()),
-        z: fp::Fp::one(),
-    };
+    let p = g1::G1::generator().mul_u128(3);
 
     // Identity: P + O = P
     let sum = p.add(&g1::G1::infinity());
     assert_eq!(sum.to_affine(), p.to_affine());
 
     // Commutativity: P + Q = Q + P
-    let q = g1::G1 {
-        x: fp::Fp::new(5u32.into()),
-        y: fp::Fp::new(1u32.into()),
-        z: fp::Fp::one(),
-    };
+    let q = g1::G1::generator().mul_u128(5);
     let sum1 = p.add(&q);
     let sum2 = q.add(&p);
     assert_eq!(sum1.to_affine(), sum2.to_affine());
@@ -91,11 +89,7 @@ fn test_g1_group_laws() {
 #[test]
 fn test_g1_scalar_multiplication() {
     // Use the known point (1, 2) which is on the curve
-    let p = g1::G1 {
-        x: fp::Fp::new(1u32.into()),
-        y: fp::Fp::new(2u32.into()),
-        z: fp::Fp::one(),
-    };
+    let p = g1::G1::from_affine(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())).unwrap();
 
     // 0 * P = O
     let res = p.mul_u128(0);
@@ -118,11 +112,7 @@ fn test_g1_scalar_multiplication() {
 
 #[test]
 fn test_g2_basic_operations() {
-    let p = g2::G2 {
-        x: fp2::Fp2::new(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())),
-        y: fp2::Fp2::new(fp::Fp::new(3u32.into()), fp::Fp::new(4u32.into())),
-        z: fp2::Fp2::one(),
-    };
+    let p = g2::G2::generator().double();
 
     // Test infinity
     let inf = g2::G2::infinity();
@@ -140,11 +130,7 @@ fn test_g2_basic_operations() {
 
 #[test]
 fn test_g2_scalar_multiplication() {
-    let p = g2::G2 {
-        x: fp2::Fp2::new(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())),
-        y: fp2::Fp2::new(fp::Fp::new(3u32.into()), fp::Fp::new(4u32.into())),
-        z: fp2::Fp2::one(),
-    };
+    let p = g2::G2::generator().double();
 
     // 0 * P = O
     let res = p.mul_scalar(&BigUint::zero());
@@ -188,49 +174,42 @@ fn test_fp12_operations() {
 #[test]
 fn test_pairing_with_infinity() {
     let inf_g1 = g1::G1::infinity();
-    let q = g2::G2 {
-        x: fp2::Fp2::new(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())),
-        y: fp2::Fp2::new(fp::Fp::new(3u32.into()), fp::Fp::new(4u32.into())),
-        z: fp2::Fp2::one(),
-    };
+    let q = g2::G2::generator().double();
 
     // e(O, Q) = 1
     let result = pairing::pairing(&inf_g1, &q);
-    assert_eq!(result, fp12::Fp12::one());
+    assert_eq!(result, gt::Gt::one());
 
     // e(P, O) = 1
-    let p = g1::G1 {
-        x: fp::Fp::new(1u32.into()),
-        y: fp::Fp::new(2u32.into()),
-        z: fp::Fp::one(),
-    };
+    let p = g1::G1::generator();
     let inf_g2 = g2::G2::infinity();
     let result = pairing::pairing(&p, &inf_g2);
-    assert_eq!(result, fp12::Fp12::one());
+    assert_eq!(result, gt::Gt::one());
 }
 
 #[test]
-#[ignore] // Might be slow or incomplete
 fn test_pairing_bilinearity_simple() {
     // Test e(2P, Q) = e(P, Q)²
-    let p = g1::G1 {
-        x: fp::Fp::new(1u32.into()),
-        y: fp::Fp::new(2u32.into()),
-        z: fp::Fp::one(),
-    };
-    let q = g2::G2 {
-        x: fp2::Fp2::new(fp::Fp::new(1u32.into()), fp::Fp::new(2u32.into())),
-        y: fp2::Fp2::new(fp::Fp::new(3u32.into()), fp::Fp::new(4u32.into())),
-        z: fp2::Fp2::one(),
-    };
+    let p = g1::G1::generator();
+    let q = g2::G2::generator();
 
     let two_p = p.mul_u128(2);
     let e_2p_q = pairing::pairing(&two_p, &q);
-    
+
     let e_p_q = pairing::pairing(&p, &q);
     let e_p_q_squared = &e_p_q * &e_p_q;
-    
-    // Note: This test might fail if the pairing implementation is incomplete
-    // It's marked as ignored for now
     assert_eq!(e_2p_q, e_p_q_squared);
 }
+
+#[test]
+fn test_defaults() {
+    assert!(fp::Fp::default().is_zero());
+    assert!(fp2::Fp2::default().is_zero());
+    assert_eq!(fp6::Fp6::default(), fp6::Fp6::zero());
+    assert_eq!(fp12::Fp12::default(), fp12::Fp12::one());
+    assert!(g1::G1::default().is_infinity());
+    assert!(g2::G2::default().is_infinity());
+    assert_eq!(g1::G1Affine::default(), g1::G1Affine::infinity());
+    assert_eq!(g2::G2Affine::default(), g2::G2Affine::infinity());
+    assert!(gt::Gt::default().is_identity());
+}
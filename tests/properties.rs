@@ -0,0 +1,144 @@
+/// Property tests for the field and group arithmetic
+use bn254::fp::Fp;
+use bn254::fp2::Fp2;
+use bn254::fp6::Fp6;
+use bn254::g1::G1;
+use bn254::g2::G2;
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+const P: &[u8] = b"21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// Uniform 256-bit integers reduced mod p, mixed with values just below p
+/// where carry and reduction bugs tend to show up
+fn fp() -> impl Strategy<Value = Fp> {
+    let p = BigUint::parse_bytes(P, 10).unwrap();
+    prop_oneof![
+        any::<[u8; 32]>().prop_map(|b| Fp::new(BigUint::from_bytes_be(&b))),
+        (1u64..1 << 16).prop_map(move |k| Fp::new(&p - k)),
+    ]
+}
+
+fn fp2() -> impl Strategy<Value = Fp2> {
+    (fp(), fp()).prop_map(|(c0, c1)| Fp2::new(c0, c1))
+}
+
+fn fp6() -> impl Strategy<Value = Fp6> {
+    (fp2(), fp2(), fp2()).prop_map(|(c0, c1, c2)| Fp6::new(c0, c1, c2))
+}
+
+fn g1() -> impl Strategy<Value = G1> {
+    any::<u64>().prop_map(|k| G1::generator().mul_scalar(&BigUint::from(k)))
+}
+
+fn g2() -> impl Strategy<Value = G2> {
+    any::<u64>().prop_map(|k| G2::generator().mul_scalar(&BigUint::from(k)))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    #[test]
+    fn fp_field_laws(a in fp(), b in fp(), c in fp()) {
+        prop_assert_eq!(a + b, b + a);
+        prop_assert_eq!(a * b, b * a);
+        prop_assert_eq!(
+            (a + b) + c,
+            a + (b + c)
+        );
+        prop_assert_eq!(
+            (a * b) * c,
+            a * (b * c)
+        );
+        prop_assert_eq!(
+            a * (b + c),
+            a * b + a * c
+        );
+        prop_assert!((a + -a).is_zero());
+        prop_assert_eq!(a - b + b, a);
+        if !a.is_zero() {
+            prop_assert_eq!(a.inv().inv(), a);
+            prop_assert_eq!(a * a.inv(), Fp::one());
+        }
+    }
+
+    #[test]
+    fn fp_biguint_roundtrip(a in fp()) {
+        prop_assert_eq!(Fp::new(a.to_biguint()), a);
+    }
+
+    #[test]
+    fn fp2_field_laws(a in fp2(), b in fp2(), c in fp2()) {
+        prop_assert_eq!(&a + &b, &b + &a);
+        prop_assert_eq!(&a * &b, &b * &a);
+        prop_assert_eq!(&(&a + &b) + &c, &a + &(&b + &c));
+        prop_assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+        prop_assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+        prop_assert!((a.clone() + -a.clone()).is_zero());
+        if !a.is_zero() {
+            prop_assert_eq!(&a * &a.inv(), Fp2::one());
+        }
+    }
+
+    #[test]
+    fn fp6_field_laws(a in fp6(), b in fp6(), c in fp6()) {
+        prop_assert_eq!(&a + &b, &b + &a);
+        prop_assert_eq!(&a * &b, &b * &a);
+        prop_assert_eq!(&(&a + &b) + &c, &a + &(&b + &c));
+        prop_assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+        prop_assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+        prop_assert_eq!(a.clone() + -a.clone(), Fp6::zero());
+        if a != Fp6::zero() {
+            prop_assert_eq!(&a * &a.inv(), Fp6::one());
+        }
+    }
+
+    #[test]
+    fn fp6_frobenius(a in fp6(), b in fp6()) {
+        // σ has order 6 on Fp6, so applying it p times is σ^(p mod 6)
+        let p = (-Fp::one()).to_biguint() + 1u32;
+        let p_mod_6 = (p % 6u32).to_u32_digits()[0] as usize;
+        let mut x = a.clone();
+        for _ in 0..6 {
+            x = x.frobenius_map(1);
+        }
+        prop_assert_eq!(&x, &a);
+        prop_assert_eq!(a.frobenius_map(p_mod_6), a.frobenius_map(1));
+
+        prop_assert_eq!(a.frobenius_map(1).frobenius_map(2), a.frobenius_map(3));
+        prop_assert_eq!(
+            (&a * &b).frobenius_map(1),
+            &a.frobenius_map(1) * &b.frobenius_map(1)
+        );
+    }
+
+    #[test]
+    fn g1_group_laws(p in g1(), q in g1(), n in 0u32..1000, m in 0u32..1000) {
+        prop_assert!(p.add(&-p).is_infinity());
+
+        let (n, m) = (BigUint::from(n), BigUint::from(m));
+        prop_assert_eq!(
+            p.add(&q).mul_scalar(&n).to_affine(),
+            p.mul_scalar(&n).add(&q.mul_scalar(&n)).to_affine()
+        );
+        prop_assert_eq!(
+            p.mul_scalar(&(&n + &m)).to_affine(),
+            p.mul_scalar(&n).add(&p.mul_scalar(&m)).to_affine()
+        );
+    }
+
+    #[test]
+    fn g2_group_laws(p in g2(), q in g2(), n in 0u32..1000, m in 0u32..1000) {
+        prop_assert!(p.add(&-p.clone()).is_infinity());
+
+        let (n, m) = (BigUint::from(n), BigUint::from(m));
+        prop_assert_eq!(
+            p.add(&q).mul_scalar(&n).to_affine(),
+            p.mul_scalar(&n).add(&q.mul_scalar(&n)).to_affine()
+        );
+        prop_assert_eq!(
+            p.mul_scalar(&(&n + &m)).to_affine(),
+            p.mul_scalar(&n).add(&p.mul_scalar(&m)).to_affine()
+        );
+    }
+}
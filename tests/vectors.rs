@@ -0,0 +1,278 @@
+/// Known-answer tests against vectors from other implementations, decoded
+/// and re-encoded through the public serialization API
+use bn254::fp::Fp;
+use bn254::fp12::Fp12;
+use bn254::fp2::Fp2;
+use bn254::fp6::Fp6;
+use bn254::g1::{G1Affine, G1};
+use bn254::g2::{G2Affine, G2};
+use bn254::pairing::{pairing, pairing_check, pairing_textbook};
+use bn254::scalar::Scalar;
+use num_bigint::BigUint;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn g1(s: &str) -> G1 {
+    G1Affine::from_uncompressed(&hex(s).try_into().unwrap())
+        .unwrap()
+        .into()
+}
+
+fn encode(p: G1) -> Vec<u8> {
+    G1Affine::from(p).to_uncompressed().to_vec()
+}
+
+const G1_GEN: &str = "0000000000000000000000000000000000000000000000000000000000000001\
+                      0000000000000000000000000000000000000000000000000000000000000002";
+
+/// (a, b, a + b), from go-ethereum's bn256Add.json
+const G1_ADD: &[(&str, &str, &str)] = &[
+    (
+        G1_GEN,
+        G1_GEN,
+        "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+         15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+    ),
+    (
+        "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+         063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266",
+        "07c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed\
+         06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7",
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+         301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915",
+    ),
+    (
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+         301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915",
+        "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+         063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266",
+        "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7\
+         21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204",
+    ),
+];
+
+/// (P, k, [k]P), from go-ethereum's bn256ScalarMul.json
+const G1_MUL: &[(&str, &str, &str)] = &[
+    (
+        "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7\
+         21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204",
+        "00000000000000000000000000000000000000000000000011138ce750fa15c2",
+        "070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c\
+         031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc",
+    ),
+    (
+        "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+         1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6",
+        "0000000000000000000000000000000000000000000000000000000000000009",
+        "1dbad7d39dbc56379f78fac1bca147dc8e66de1b9d183c7b167351bfe0aeab74\
+         2cd757d51289cd8dbd0acf9e673ad67d0f0a89f912af47ed1be53664f5692575",
+    ),
+];
+
+/// The G2 generator in EIP-197 encoding, x.c1 || x.c0 || y.c1 || y.c0
+const G2_GEN: &str = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+                      1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+                      090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+                      12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+
+/// (k, [k]G2) in EIP-197 encoding, from py_ecc's `bn128.multiply(G2, k)`:
+/// small multiples, the go-ethereum G1 scalar above, 2¹²⁸ + 1, r - 1 (so
+/// -G2) and a full-width scalar
+const G2_MUL: &[(&str, &str)] = &[
+    (
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "203e205db4f19b37b60121b83a7333706db86431c6d835849957ed8c3928ad79\
+         27dc7234fd11d3e8c36c59277c3e6f149d5cd3cfa9a62aee49f8130962b4b3b9\
+         195e8aa5b7827463722b8c153931579d3505566b4edf48d498e185f0509de152\
+         04bb53b8977e5f92a0bc372742c4830944a59b4fe6b1c0466e2a6dad122b5d2e",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "1014772f57bb9742735191cd5dcfe4ebbc04156b6878a0a7c9824f32ffb66e85\
+         06064e784db10e9051e52826e192715e8d7e478cb09a5e0012defa0694fbc7f5\
+         021e2335f3354bb7922ffcc2f38d3323dd9453ac49b55441452aeaca147711b2\
+         058e1d5681b5b9e0074b0f9c8d2c68a069b920d74521e79765036d57666c5597",
+    ),
+    (
+        "000000000000000000000000000000000000000000000000000000000000001f",
+        "2aeec44fe814d216dbf395d867287888ff68759bb9d5a0167109501aa9eb15dd\
+         1ae8bf2213b5f02d55e2ef7cb6aa28e4a5c0b10f8145e5f3e365e5d6e7938e99\
+         012ba32451dff8aba8f20e5294fc0e4a53f9dabbfcabe7e595bf95abdfa25be8\
+         033ccc1affd4d27701ea9dc524dd3e7a8848bcae31f9eeade63b613d662fd900",
+    ),
+    (
+        "00000000000000000000000000000000000000000000000011138ce750fa15c2",
+        "0c1358075a4af3ce4567a4389925db643e4a8ec08812bc11af03331ef09189e9\
+         24e3607971fee5a16e42e2f6e6c53cebcca187b4f2058ee560b58591c648b024\
+         150a3fc92d718bc13a1ad6e10204531b853189589e0aeb44355340b9adb0b477\
+         09832da3430ab33f2f76b52506fe0133e23549a55a3918f23e559db06d455a16",
+    ),
+    (
+        "0000000000000000000000000000000100000000000000000000000000000001",
+        "215edbf16aa1b322b0a2920e94d9188ce2237b75dad319575fefc030057ff52f\
+         1005eca266ec7897e6db5be1924c09620f07b886023d06599f5e62f4a05a770a\
+         2a96da77575690348b297fbf7b03b72f6ebb43009a258336f1bf3e3491239c7d\
+         1ffa15df292a3e322e61a1af9b6f37d312fd2e0825c22bf1256685915240ae73",
+    ),
+    (
+        "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+         1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+         275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec\
+         1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d",
+    ),
+    (
+        "2a1c5a6f3ae1e9a8e1b0b5b3c7a8e4d0f2e9b6c1a0d3e5f7b9c2d4e6f8a0b1c3",
+        "23dfda33de1e1e7cba68949f54841e83d2394fdbff75fb7e2c74f2af3d6b35a0\
+         0d92b9468b456cc85555ff5303ce951fee7432c0bc8d24df09f5b9711981d342\
+         253dea543966de5fe09c6d5d125c22ffa0cc0424984d9f2447a6a4159ebbf917\
+         23922a6656169bc54dfea2b6c325bd2a704574020360c88e5a3dfcc7c25d5c90",
+    ),
+];
+
+/// Pairing check input and result, from go-ethereum's bn256Pairing.json
+/// ("jeff1")
+const PAIRING_CHECK: (&str, bool) = (
+    "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+     3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+     209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+     04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+     2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+     120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+     111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+     2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411\
+     198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+     1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+     090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+     12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    true,
+);
+
+/// e(G1, G2) under the textbook final exponent (p¹² - 1)/r, as computed by
+/// py_ecc's `bn128.pairing(G2, G1)`. py_ecc works in Fp[w]/(w¹² - 18w⁶ + 82)
+/// with u = w⁶ - 9, the same w as here, so its coefficients c_k of w^k map
+/// to the Fp2 coefficient of w^e·v^j as c_(2j+e) + c_(2j+e+6)·(9 + u).
+/// Listed as (c0, c1) of each Fp2 in the order c0.c0, c0.c1, c0.c2, c1.c0,
+/// c1.c1, c1.c2.
+const E_G1_G2: [(&str, &str); 6] = [
+    (
+        "12c70e90e12b7874510cd1707e8856f71bf7f61d72631e268fca81000db9a1f5",
+        "084f330485b09e866bc2f2ea2b897394deaf3f12aa31f28cb0552990967d4704",
+    ),
+    (
+        "0e841c2ac18a4003ac9326b9558380e0bc27fdd375e3605f96b819a358d34bde",
+        "2067586885c3318eeffa1938c754fe3c60224ee5ae15e66af6b5104c47c8c5d8",
+    ),
+    (
+        "01676555de427abc409c4a394bc5426886302996919d4bf4bdd02236e14b3636",
+        "2b03614464f04dd772d86df88674c270ffc8747ea13e72da95e3594468f222c4",
+    ),
+    (
+        "2c53748bcd21a7c038fb30ddc8ac3bf0af25d7859cfbc12c30c866276c565909",
+        "27ed208e7a0b55ae6e710bbfbd2fd922669c026360e37cc5b2ab862411536104",
+    ),
+    (
+        "1ad9db1937fd72f4ac462173d31d3d6117411fa48dba8d499d762b47edb3b54a",
+        "279db296f9d479292532c7c493d8e0722b6efae42158387564889c79fc038ee3",
+    ),
+    (
+        "0dc26f240656bbe2029bd441d77c221f0ba4c70c94b29b5f17f0f6d08745a069",
+        "108c19d15f9446f744d0f110405d3856d6cc3bda6c4d537663729f5257628417",
+    ),
+];
+
+#[test]
+fn test_g1_add_vectors() {
+    for (a, b, sum) in G1_ADD {
+        assert_eq!(encode(g1(a).add(&g1(b))), hex(sum));
+    }
+}
+
+#[test]
+fn test_g1_mul_vectors() {
+    for (p, k, product) in G1_MUL {
+        let k = BigUint::from_bytes_be(&hex(k));
+        assert_eq!(encode(g1(p).mul_scalar(&k)), hex(product));
+        assert_eq!(encode(g1(p).mul_scalar_glv(&k)), hex(product));
+    }
+}
+
+#[test]
+fn test_g2_generator_vector() {
+    let g = G2Affine::from_uncompressed(&hex(G2_GEN).try_into().unwrap()).unwrap();
+    assert_eq!(G2::from(g.clone()), G2::generator());
+
+    // Small multiples agree between scalar multiplication and repeated
+    // addition, and survive an encoding roundtrip
+    let g = G2::generator();
+    let mut acc = G2::infinity();
+    for k in 1u32..=8 {
+        acc = acc.add(&g);
+        let q = G2Affine::from(g.mul_scalar(&BigUint::from(k)));
+        assert_eq!(q, G2Affine::from(acc.clone()));
+        assert_eq!(G2Affine::from_uncompressed(&q.to_uncompressed()), Ok(q));
+    }
+}
+
+#[test]
+fn test_g2_mul_vectors() {
+    for (k, product) in G2_MUL {
+        let k = BigUint::from_bytes_be(&hex(k));
+        let g = G2::generator();
+        for q in [
+            g.mul_scalar(&k),
+            g.mul_scalar_endo(&k),
+            g.mul_scalar_wnaf(&k, 4),
+            G2::mul_gen(&Scalar::from(k.clone())),
+        ] {
+            assert_eq!(G2Affine::from(q).to_uncompressed().to_vec(), hex(product));
+        }
+    }
+}
+
+#[test]
+fn test_pairing_check_vector() {
+    let input = hex(PAIRING_CHECK.0);
+    let points: Vec<(G1, G2)> = input
+        .chunks(192)
+        .map(|c| {
+            let p = G1Affine::from_uncompressed(c[..64].try_into().unwrap()).unwrap();
+            let q = G2Affine::from_uncompressed(c[64..].try_into().unwrap()).unwrap();
+            (p.into(), q.into())
+        })
+        .collect();
+    let pairs: Vec<_> = points.iter().map(|(p, q)| (p, q)).collect();
+    assert_eq!(pairing_check(&pairs), PAIRING_CHECK.1);
+
+    // Swapping the G1 points breaks the relation
+    let swapped = [(pairs[1].0, pairs[0].1), (pairs[0].0, pairs[1].1)];
+    assert!(!pairing_check(&swapped));
+}
+
+#[test]
+fn test_pairing_value_vector() {
+    let fp2 = |(c0, c1): (&str, &str)| {
+        Fp2::new(
+            Fp::from_bytes_be(&hex(c0)).unwrap(),
+            Fp::from_bytes_be(&hex(c1)).unwrap(),
+        )
+    };
+    let [a0, a1, a2, b0, b1, b2] = E_G1_G2.map(fp2);
+    let reference = Fp12::new(Fp6::new(a0, a1, a2), Fp6::new(b0, b1, b2));
+    assert_eq!(
+        *pairing_textbook(&G1::generator(), &G2::generator()).inner(),
+        reference
+    );
+
+    // The final exponentiation raises to m·(p¹² - 1)/r with
+    // m = 2u(6u² + 3u + 1), see `pairing::final_exponentiation`
+    let u = BigUint::from(bn254::BN_PARAM);
+    let m = BigUint::from(2u32) * &u * (BigUint::from(6u32) * &u * &u + 3u32 * &u + 1u32);
+    let e = pairing(&G1::generator(), &G2::generator());
+    assert_eq!(*e.inner(), reference.pow(&m));
+}